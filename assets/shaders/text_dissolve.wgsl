@@ -0,0 +1,25 @@
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+@group(1) @binding(0) var<uniform> params: vec4<f32>;
+@group(1) @binding(1) var<uniform> uv_rect: vec4<f32>;
+@group(1) @binding(2) var<uniform> color: vec4<f32>;
+@group(1) @binding(3) var atlas_texture: texture_2d<f32>;
+@group(1) @binding(4) var atlas_sampler: sampler;
+
+fn hash(p: vec2<f32>) -> f32 {
+    return fract(sin(dot(p, vec2<f32>(12.9898, 78.233))) * 43758.5453);
+}
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    // `in.uv` goes from 0 to 1 over the glyph, mapped into the glyph's rect in the atlas
+    let atlas_uv = mix(uv_rect.xy, uv_rect.zw, in.uv);
+    let glyph = textureSample(atlas_texture, atlas_sampler, atlas_uv);
+    // params.x is the dissolve threshold, params.y the size of the noise cells in atlas pixels
+    let atlas_size = vec2<f32>(textureDimensions(atlas_texture));
+    let noise = hash(floor(atlas_uv * atlas_size / params.y));
+    if noise < params.x {
+        discard;
+    }
+    return glyph * color;
+}
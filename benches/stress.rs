@@ -0,0 +1,180 @@
+//! Frame times of the crate's systems with thousands of texts, run with
+//! `cargo bench --bench stress`.
+
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use bevy::prelude::*;
+use bevy::render::view::NoFrustumCulling;
+use bevy_mod_ui_independent_text::*;
+use common::*;
+
+const FRAMES: u32 = 100;
+
+/// Prints the mean and slowest time of `frame`, after running it once to warm up.
+fn time(name: &str, mut frame: impl FnMut()) {
+    frame();
+    let mut total = Duration::ZERO;
+    let mut slowest = Duration::ZERO;
+    for _ in 0..FRAMES {
+        let start = Instant::now();
+        frame();
+        let elapsed = start.elapsed();
+        total += elapsed;
+        slowest = slowest.max(elapsed);
+    }
+    let mean = total.as_secs_f64() * 1000. / FRAMES as f64;
+    let slowest = slowest.as_secs_f64() * 1000.;
+    println!("{name:<40} {mean:>8.3} ms, slowest {slowest:>8.3} ms");
+}
+
+/// Spawns `count` labels on a grid covering the window, with `distinct` different values.
+fn spawn_labels(app: &mut App, count: usize, distinct: usize) {
+    let font = font(app);
+    for index in 0..count {
+        let position = Vec3::new((index % 50) as f32 * 16., (index / 50) as f32 * 12., 0.);
+        spawn_text(
+            app,
+            UiText::from_section(format!("label {:04}", index % distinct), style(&font, 12.)),
+            Transform::from_translation(position),
+        );
+    }
+    app.update();
+}
+
+/// Every glyph of the labels is inserted into the extracted nodes in one go, the map is
+/// reserved for them first.
+fn extraction() {
+    let mut app = app(1.);
+    spawn_labels(&mut app, 2000, 2000);
+    time("extract 2000 labels", || {
+        black_box(extract(&mut app));
+    });
+}
+
+/// 2000 labels with 20 different values relaid out every frame, with and without the
+/// [`UiTextLayoutCache`].
+fn layout_cache() {
+    for cached in [false, true] {
+        let mut app = app(1.);
+        if cached {
+            app.insert_resource(UiTextLayoutCache::default());
+        }
+        spawn_labels(&mut app, 2000, 20);
+        let name = if cached {
+            "relayout 2000 labels, cached"
+        } else {
+            "relayout 2000 labels, uncached"
+        };
+        time(name, || {
+            let world = app.world_mut();
+            let mut texts = world.query::<&mut UiText>();
+            for mut text in texts.iter_mut(world) {
+                text.set_changed();
+            }
+            app.update();
+        });
+    }
+}
+
+/// 2000 labels of which 100 are on screen, extracted with and without the
+/// [`UiTextViewportCulling`].
+fn viewport_culling() {
+    for culled in [false, true] {
+        let mut app = app(1.);
+        if culled {
+            app.insert_resource(UiTextViewportCulling::default());
+        }
+        spawn_labels(&mut app, 2000, 2000);
+        // moves all but the first two rows of the grid below the window, the camera's frustum
+        // culling is turned off so the visibility checks keep every label
+        let world = app.world_mut();
+        let mut labels = world.query_filtered::<(Entity, &mut Transform), With<UiText>>();
+        let mut entities = Vec::new();
+        for (entity, mut transform) in labels.iter_mut(world) {
+            if 12. < transform.translation.y {
+                transform.translation.y += 600.;
+            }
+            entities.push(entity);
+        }
+        for entity in entities {
+            world.entity_mut(entity).insert(NoFrustumCulling);
+        }
+        app.update();
+        let name = if culled {
+            "extract 2000 labels, 100 shown, culled"
+        } else {
+            "extract 2000 labels, 100 shown"
+        };
+        time(name, || {
+            black_box(extract(&mut app));
+        });
+    }
+}
+
+#[derive(Component)]
+struct SpawnedFrame(u32);
+
+/// 50 damage numbers a frame, each shown for a frame, spawned and despawned or acquired from a
+/// pool.
+fn pooling() {
+    for pooled in [false, true] {
+        let mut app = app(1.);
+        let font = font(&mut app);
+        if pooled {
+            let mut pool = UiTextPool::default();
+            pool.lifetime = Duration::ZERO;
+            app.insert_resource(pool);
+        }
+        app.add_systems(
+            Update,
+            move |mut commands: Commands,
+                  mut frame: Local<u32>,
+                  mut pool: Option<ResMut<UiTextPool>>,
+                  spawned: Query<(Entity, &SpawnedFrame)>| {
+                *frame += 1;
+                for index in 0..50 {
+                    let value = format!("{}", (*frame * 7 + index) % 100);
+                    let style = style(&font, 16.);
+                    let translation = Vec3::new(index as f32 * 16., 300., 0.);
+                    match &mut pool {
+                        Some(pool) => {
+                            pool.acquire(&mut commands, value, style, translation);
+                        }
+                        None => {
+                            commands.spawn((
+                                IndependentTextBundle {
+                                    text: UiText::from_section(value, style),
+                                    transform: Transform::from_translation(translation),
+                                    ..default()
+                                },
+                                SpawnedFrame(*frame),
+                            ));
+                        }
+                    }
+                }
+                for (entity, spawned) in &spawned {
+                    if spawned.0 < *frame {
+                        commands.entity(entity).despawn();
+                    }
+                }
+            },
+        );
+        let name = if pooled {
+            "50 damage numbers a frame, pooled"
+        } else {
+            "50 damage numbers a frame, spawned"
+        };
+        time(name, || app.update());
+    }
+}
+
+fn main() {
+    extraction();
+    layout_cache();
+    viewport_culling();
+    pooling();
+}
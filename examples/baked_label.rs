@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+use bevy_mod_ui_independent_text::*;
+
+#[derive(Resource)]
+struct Label(UiText);
+
+#[derive(Component)]
+struct Spin;
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0., 0., 4.).looking_at(Vec3::ZERO, Vec3::Y),
+        ..Default::default()
+    });
+    commands.insert_resource(Label(UiText::from_section(
+        "Nameplate",
+        TextStyle {
+            font: asset_loader.load("Topaz-8.ttf"),
+            font_size: 32.0,
+            color: Color::WHITE,
+        },
+    )));
+}
+
+fn bake_label(
+    mut commands: Commands,
+    label: Option<Res<Label>>,
+    mut rasterizer: UiTextRasterizer,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Some(label) = label else {
+        return;
+    };
+    // fails until the font has loaded
+    let Ok(baked) = rasterizer.rasterize(&label.0, None, 1.) else {
+        return;
+    };
+    let size = baked.size.as_vec2() / baked.size.x as f32 * 3.;
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Rectangle::new(size.x, size.y)),
+            material: materials.add(StandardMaterial {
+                base_color_texture: Some(baked.image),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                double_sided: true,
+                cull_mode: None,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        Spin,
+    ));
+    commands.remove_resource::<Label>();
+}
+
+fn spin(time: Res<Time>, mut query: Query<&mut Transform, With<Spin>>) {
+    for mut transform in &mut query {
+        transform.rotate_y(time.delta_seconds());
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (bake_label, spin))
+        .run();
+}
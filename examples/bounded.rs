@@ -1,5 +1,4 @@
 use bevy::prelude::*;
-use bevy::text::Text2dBounds;
 use bevy_mod_ui_independent_text::*;
 
 fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
@@ -21,9 +20,7 @@ fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
             translation: Vec3::new(400., 300., 100.),
             ..Default::default()
         },
-        text_2d_bounds: Text2dBounds {
-            size: Vec2::new(120., 300.),
-        },
+        bounds: UiTextBounds::new(120., 300.),
         ..Default::default()
     });
 }
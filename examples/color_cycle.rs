@@ -0,0 +1,63 @@
+//! Demo scene style scroller text cycling through a palette with `ColorCycle`.
+use bevy::prelude::*;
+use bevy_mod_ui_independent_text::*;
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle {
+        camera: Camera {
+            clear_color: ClearColorConfig::Custom(Color::BLACK),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let font = asset_loader.load("Topaz-8.ttf");
+    commands.spawn((
+        IndependentTextBundle {
+            text: UiText::from_section(
+                "GREETINGS TO ALL THE CREWS",
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 48.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(400., 260., 0.),
+            ..Default::default()
+        },
+        ColorCycle::default(),
+        WaveText {
+            amplitude: 10.,
+            frequency: 0.06,
+            speed: 0.8,
+        },
+    ));
+    commands.spawn((
+        IndependentTextBundle {
+            text: UiText::from_section(
+                "* no relayouts were harmed *",
+                TextStyle {
+                    font,
+                    font_size: 24.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(400., 360., 0.),
+            ..Default::default()
+        },
+        ColorCycle {
+            palette: (0..12)
+                .map(|step| Color::hsl(30. * step as f32, 0.9, 0.6))
+                .collect(),
+            speed: 12.,
+            per_glyph_phase: 0.5,
+        },
+    ));
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
@@ -0,0 +1,58 @@
+//! Click anywhere to spawn a burst of damage numbers.
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_mod_ui_independent_text::*;
+
+#[derive(Resource)]
+struct DamageFont(Handle<Font>);
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    commands.insert_resource(DamageFont(asset_loader.load("Topaz-8.ttf")));
+}
+
+fn spawn_damage_numbers(
+    mut commands: Commands,
+    mouse: Res<ButtonInput<MouseButton>>,
+    font: Res<DamageFont>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut hits: Local<u32>,
+) {
+    if !mouse.pressed(MouseButton::Left) {
+        return;
+    }
+    let Some(cursor) = window_query
+        .get_single()
+        .ok()
+        .and_then(Window::cursor_position)
+    else {
+        return;
+    };
+    for _ in 0..10 {
+        *hits += 1;
+        let damage = 10 + *hits * 37 % 200;
+        let critical = damage > 180;
+        commands.spawn(DamageNumberBundle::new(
+            damage.to_string(),
+            TextStyle {
+                font: font.0.clone(),
+                font_size: if critical { 32. } else { 20. },
+                color: if critical {
+                    Color::srgb(1., 0.8, 0.2)
+                } else {
+                    Color::WHITE
+                },
+            },
+            cursor.extend(100.),
+        ));
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, spawn_damage_numbers)
+        .run();
+}
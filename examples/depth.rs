@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+use bevy_mod_ui_independent_text::*;
+
+fn label(value: &str, font: &Handle<Font>, color: Color) -> UiText {
+    UiText::from_section(
+        value,
+        TextStyle {
+            font: font.clone(),
+            font_size: 32.0,
+            color,
+        },
+    )
+}
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    let font = asset_loader.load("Topaz-8.ttf");
+
+    // texts are stacked with the UI nodes by the integer part of their z translation
+    commands.spawn(IndependentTextBundle {
+        text: label("Below the panel", &font, Color::srgb(1., 0.3, 0.3)),
+        transform: Transform::from_xyz(300., 200., 0.),
+        ..Default::default()
+    });
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .with_children(|root| {
+            root.spawn(NodeBundle {
+                style: Style {
+                    width: Val::Percent(50.),
+                    height: Val::Percent(50.),
+                    ..Default::default()
+                },
+                background_color: Color::srgb(0.2, 0.2, 0.4).into(),
+                ..Default::default()
+            })
+            .with_children(|panel| {
+                // positioned from the panel's top left corner, stays on the panel when resizing
+                panel.spawn(IndependentTextBundle {
+                    text: label("Glued to the panel", &font, Color::WHITE),
+                    transform: Transform::from_xyz(160., 24., 100.),
+                    ..Default::default()
+                });
+            });
+        });
+    commands.spawn(IndependentTextBundle {
+        text: label("Above the panel", &font, Color::srgb(0.3, 1., 0.3)),
+        transform: Transform::from_xyz(500., 400., 100.),
+        ..Default::default()
+    });
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
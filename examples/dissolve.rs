@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+use bevy::render::render_resource::{AsBindGroup, ShaderRef};
+use bevy_mod_ui_independent_text::*;
+
+#[derive(Asset, TypePath, AsBindGroup, Clone, Debug, Default)]
+struct DissolveMaterial {
+    /// x: dissolve threshold, y: noise cell size
+    #[uniform(0)]
+    params: Vec4,
+    /// The glyph's rect in its atlas, min in xy and max in zw
+    #[uniform(1)]
+    uv_rect: Vec4,
+    #[uniform(2)]
+    color: LinearRgba,
+    #[texture(3)]
+    #[sampler(4)]
+    atlas: Handle<Image>,
+}
+
+impl UiMaterial for DissolveMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/text_dissolve.wgsl".into()
+    }
+}
+
+impl UiTextGlyphMaterial for DissolveMaterial {
+    fn set_glyph(&mut self, glyph: &UiTextMaterialGlyph) {
+        self.uv_rect = Vec4::new(
+            glyph.uv_rect.min.x,
+            glyph.uv_rect.min.y,
+            glyph.uv_rect.max.x,
+            glyph.uv_rect.max.y,
+        );
+        self.color = glyph.color;
+        self.atlas = glyph.texture.clone();
+    }
+}
+
+fn setup(
+    mut commands: Commands,
+    asset_loader: Res<AssetServer>,
+    mut materials: ResMut<Assets<DissolveMaterial>>,
+) {
+    commands.spawn(Camera2dBundle::default());
+    commands.spawn((
+        IndependentTextBundle {
+            text: UiText::from_section(
+                "This message will self-destruct",
+                TextStyle {
+                    font: asset_loader.load("Topaz-8.ttf"),
+                    font_size: 32.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(400., 300., 100.),
+            ..Default::default()
+        },
+        UiTextMaterial(materials.add(DissolveMaterial {
+            params: Vec4::new(0., 2., 0., 0.),
+            ..Default::default()
+        })),
+    ));
+}
+
+/// Only the text's material is animated, the plugin copies it into the material of each glyph.
+fn dissolve(
+    time: Res<Time>,
+    mut materials: ResMut<Assets<DissolveMaterial>>,
+    text_query: Query<&UiTextMaterial<DissolveMaterial>>,
+) {
+    for material in &text_query {
+        if let Some(material) = materials.get_mut(&material.0) {
+            material.params.x = 0.5 + 0.5 * time.elapsed_seconds().sin();
+        }
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_plugins(UiTextMaterialPlugin::<DissolveMaterial>::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, dissolve)
+        .run();
+}
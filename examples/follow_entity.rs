@@ -0,0 +1,66 @@
+//! A nameplate following a moving sprite with `FollowEntity`.
+//! Press space to despawn the sprite, which despawns its nameplate too.
+use bevy::prelude::*;
+use bevy_mod_ui_independent_text::*;
+
+#[derive(Component)]
+struct Player;
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    let player = commands
+        .spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgb(0.2, 0.4, 1.),
+                    custom_size: Some(Vec2::splat(50.)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Player,
+        ))
+        .id();
+    commands.spawn((
+        IndependentTextBundle {
+            text: UiText::from_section(
+                "Player One",
+                TextStyle {
+                    font: asset_loader.load("Topaz-8.ttf"),
+                    font_size: 16.0,
+                    color: Color::WHITE,
+                },
+            ),
+            ..Default::default()
+        },
+        FollowEntity::new(player).with_offset(40. * Vec3::Y),
+    ));
+}
+
+fn move_player(time: Res<Time>, mut query: Query<&mut Transform, With<Player>>) {
+    let t = time.elapsed_seconds();
+    for mut transform in &mut query {
+        transform.translation = Vec3::new(200. * t.cos(), 150. * (2. * t).sin(), 0.);
+    }
+}
+
+fn despawn_player(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    query: Query<Entity, With<Player>>,
+) {
+    if keyboard.just_pressed(KeyCode::Space) {
+        for entity in &query {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (move_player, despawn_player))
+        .run();
+}
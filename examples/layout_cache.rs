@@ -0,0 +1,74 @@
+//! Spawns hundreds of identical "+10" labels every second. Press space to toggle the
+//! `UiTextLayoutCache` and compare the frame times logged to the console.
+use bevy::diagnostic::{FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin};
+use bevy::prelude::*;
+use bevy_mod_ui_independent_text::*;
+
+#[derive(Resource)]
+struct LabelFont(Handle<Font>);
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    commands.insert_resource(LabelFont(asset_loader.load("Topaz-8.ttf")));
+    commands.insert_resource(UiTextLayoutCache::default());
+}
+
+fn toggle_cache(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    cache: Option<Res<UiTextLayoutCache>>,
+) {
+    if !keyboard.just_pressed(KeyCode::Space) {
+        return;
+    }
+    match cache {
+        Some(cache) => {
+            info!(
+                "Layout cache disabled, {} hits and {} misses",
+                cache.hits(),
+                cache.misses()
+            );
+            commands.remove_resource::<UiTextLayoutCache>();
+        }
+        None => {
+            info!("Layout cache enabled");
+            commands.insert_resource(UiTextLayoutCache::default());
+        }
+    }
+}
+
+fn spawn_labels(
+    mut commands: Commands,
+    font: Res<LabelFont>,
+    window_query: Query<&Window>,
+    mut count: Local<u32>,
+) {
+    let Ok(window) = window_query.get_single() else {
+        return;
+    };
+    let size = window.size();
+    for _ in 0..20 {
+        *count += 1;
+        let x = (*count * 7919 % 1000) as f32 / 1000. * size.x;
+        let y = (*count * 104729 % 1000) as f32 / 1000. * size.y;
+        commands.spawn(DamageNumberBundle::new(
+            "+10",
+            TextStyle {
+                font: font.0.clone(),
+                font_size: 20.,
+                color: Color::srgb(0.4, 1., 0.4),
+            },
+            Vec3::new(x, y, 100.),
+        ));
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins((FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin::default()))
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (toggle_cache, spawn_labels))
+        .run();
+}
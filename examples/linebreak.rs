@@ -0,0 +1,72 @@
+//! The same text laid out in the same bounds with each `BreakLineOn` behavior.
+use bevy::prelude::*;
+use bevy::text::BreakLineOn;
+use bevy_mod_ui_independent_text::*;
+
+const BOUNDS: Vec2 = Vec2::new(160., 200.);
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    let font = asset_loader.load("Topaz-8.ttf");
+    for (i, (linebreak_behavior, label)) in [
+        (BreakLineOn::WordBoundary, "WordBoundary"),
+        (BreakLineOn::AnyCharacter, "AnyCharacter"),
+        (BreakLineOn::NoWrap, "NoWrap"),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let x = 150. + 250. * i as f32;
+        commands.spawn(IndependentTextBundle {
+            text: UiText::from_section(
+                label,
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 16.,
+                    color: Color::srgb(0.6, 0.6, 0.6),
+                },
+            ),
+            transform: Transform::from_xyz(x, 150., 100.),
+            ..Default::default()
+        });
+        commands.spawn(IndependentTextBundle {
+            text: UiText(Text {
+                sections: vec![TextSection::new(
+                    "The quick brown fox jumped over the extraordinarily lazy dog",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 16.,
+                        color: Color::WHITE,
+                    },
+                )],
+                justify: JustifyText::Left,
+                linebreak_behavior,
+            }),
+            transform: Transform::from_xyz(x, 300., 100.),
+            bounds: UiTextBounds { size: BOUNDS },
+            ..Default::default()
+        });
+        // outline of the bounds
+        commands.spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(x - 0.5 * BOUNDS.x),
+                top: Val::Px(300. - 0.5 * BOUNDS.y),
+                width: Val::Px(BOUNDS.x),
+                height: Val::Px(BOUNDS.y),
+                border: UiRect::all(Val::Px(1.)),
+                ..Default::default()
+            },
+            border_color: Color::srgb(0.3, 0.3, 0.8).into(),
+            ..Default::default()
+        });
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
@@ -0,0 +1,73 @@
+use bevy::prelude::*;
+use bevy_mod_ui_independent_text::*;
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Component)]
+struct Nameplate;
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgb(0.2, 0.4, 1.),
+                custom_size: Some(Vec2::splat(50.)),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        Player,
+    ));
+    commands.spawn((
+        IndependentTextBundle {
+            text: UiText::from_section(
+                "Player One",
+                TextStyle {
+                    font: asset_loader.load("Topaz-8.ttf"),
+                    font_size: 16.0,
+                    color: Color::WHITE,
+                },
+            ),
+            ..Default::default()
+        },
+        Nameplate,
+    ));
+}
+
+fn move_player(time: Res<Time>, mut query: Query<&mut Transform, With<Player>>) {
+    let t = time.elapsed_seconds();
+    for mut transform in &mut query {
+        transform.translation = Vec3::new(200. * t.cos(), 150. * (2. * t).sin(), 0.);
+    }
+}
+
+fn place_nameplate(
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    player_query: Query<&Transform, (With<Player>, Without<Nameplate>)>,
+    mut nameplate_query: Query<&mut Transform, With<Nameplate>>,
+) {
+    let Ok((camera, camera_transform)) = camera_query.get_single() else {
+        return;
+    };
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+    let above_player = player_transform.translation + 40. * Vec3::Y;
+    for mut transform in &mut nameplate_query {
+        if let Some(translation) = world_to_ui_text_position(camera, camera_transform, above_player)
+        {
+            transform.translation = translation;
+        }
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (move_player, place_nameplate).chain())
+        .run();
+}
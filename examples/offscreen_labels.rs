@@ -0,0 +1,62 @@
+//! Benchmarks extracting thousands of labels that are mostly off screen, press space to toggle
+//! `UiTextViewportCulling`. The frame time and extracted glyphs are logged every second.
+use bevy::diagnostic::{FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin};
+use bevy::prelude::*;
+use bevy_mod_ui_independent_text::*;
+
+const LABELS: u32 = 10_000;
+const SPREAD: f32 = 20_000.;
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    let font = asset_loader.load("Topaz-8.ttf");
+    for index in 0..LABELS {
+        // a deterministic scatter, most of it outside a 1280x720 window
+        let x = (index * 7919 % LABELS) as f32 / LABELS as f32 * SPREAD;
+        let y = (index * 104_729 % LABELS) as f32 / LABELS as f32 * SPREAD;
+        commands.spawn(IndependentTextBundle {
+            text: UiText::from_section(
+                format!("Label {index}"),
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 16.,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(x, y, 100.),
+            ..Default::default()
+        });
+    }
+    commands.insert_resource(UiTextViewportCulling::default());
+}
+
+fn toggle_culling(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    culling: Option<Res<UiTextViewportCulling>>,
+) {
+    if !keys.just_pressed(KeyCode::Space) {
+        return;
+    }
+    if culling.is_some() {
+        commands.remove_resource::<UiTextViewportCulling>();
+        info!("Viewport culling off");
+    } else {
+        commands.insert_resource(UiTextViewportCulling::default());
+        info!("Viewport culling on");
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins((
+            IndependentTextPlugin,
+            IndependentTextDiagnosticsPlugin,
+            FrameTimeDiagnosticsPlugin,
+            LogDiagnosticsPlugin::default(),
+        ))
+        .add_systems(Startup, setup)
+        .add_systems(Update, toggle_culling)
+        .run();
+}
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use bevy::text::BreakLineOn;
+use bevy_mod_ui_independent_text::*;
+
+const TEXT: &str = "Explicit newlines always start a new line.\n\
+    Soft wrapping only happens at the bounds, depending on the linebreak behavior.\n\n\
+    This is a second paragraph.";
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    let style = TextStyle {
+        font: asset_loader.load("Topaz-8.ttf"),
+        font_size: 16.0,
+        color: Color::WHITE,
+    };
+    for (i, linebreak_behavior) in [BreakLineOn::WordBoundary, BreakLineOn::NoWrap]
+        .into_iter()
+        .enumerate()
+    {
+        let mut text = UiText::from_section(TEXT, style.clone());
+        text.set_justify(JustifyText::Left);
+        text.set_linebreak_behavior(linebreak_behavior);
+        commands.spawn(IndependentTextBundle {
+            text,
+            bounds: UiTextBounds::new(300., 400.),
+            transform: Transform::from_xyz(400., 150. + 300. * i as f32, 100.),
+            ..Default::default()
+        });
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
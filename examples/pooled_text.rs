@@ -0,0 +1,102 @@
+//! Stress test spawning hundreds of short lived texts per second.
+//!
+//! Press space to switch between recycling the texts with a `UiTextPool` and spawning and
+//! despawning an entity for each text. Frame times are logged to the console.
+use std::time::Duration;
+
+use bevy::diagnostic::{FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin};
+use bevy::prelude::*;
+use bevy_mod_ui_independent_text::*;
+
+const TEXTS_PER_FRAME: u32 = 20;
+const LIFETIME: Duration = Duration::from_millis(800);
+
+#[derive(Resource)]
+struct StressFont(Handle<Font>);
+
+#[derive(Resource, Default)]
+struct UsePool(bool);
+
+#[derive(Component)]
+struct Expires(Timer);
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    commands.insert_resource(StressFont(asset_loader.load("Topaz-8.ttf")));
+    let mut pool = UiTextPool::new(1024);
+    pool.lifetime = LIFETIME;
+    commands.insert_resource(pool);
+}
+
+fn toggle_pool(keyboard: Res<ButtonInput<KeyCode>>, mut use_pool: ResMut<UsePool>) {
+    if keyboard.just_pressed(KeyCode::Space) {
+        use_pool.0 = !use_pool.0;
+        info!("pooling: {}", use_pool.0);
+    }
+}
+
+fn spawn_texts(
+    mut commands: Commands,
+    mut pool: ResMut<UiTextPool>,
+    use_pool: Res<UsePool>,
+    font: Res<StressFont>,
+    window_query: Query<&Window>,
+    mut count: Local<u32>,
+) {
+    let Ok(window) = window_query.get_single() else {
+        return;
+    };
+    let size = window.size();
+    for _ in 0..TEXTS_PER_FRAME {
+        *count = count.wrapping_add(1);
+        let value = (*count % 100).to_string();
+        let style = TextStyle {
+            font: font.0.clone(),
+            font_size: 20.,
+            color: Color::WHITE,
+        };
+        let translation = Vec3::new(
+            (*count * 7919 % 1000) as f32 / 1000. * size.x,
+            (*count * 104_729 % 1000) as f32 / 1000. * size.y,
+            100.,
+        );
+        if use_pool.0 {
+            pool.acquire(&mut commands, value, style, translation);
+        } else {
+            commands.spawn((
+                IndependentTextBundle {
+                    text: UiText::from_section(value, style),
+                    transform: Transform::from_translation(translation),
+                    ..Default::default()
+                },
+                Expires(Timer::new(LIFETIME, TimerMode::Once)),
+            ));
+        }
+    }
+}
+
+fn despawn_expired(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Expires)>,
+) {
+    for (entity, mut expires) in &mut query {
+        if expires.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            FrameTimeDiagnosticsPlugin,
+            LogDiagnosticsPlugin::default(),
+        ))
+        .add_plugins(IndependentTextPlugin)
+        .init_resource::<UsePool>()
+        .add_systems(Startup, setup)
+        .add_systems(Update, (toggle_pool, spawn_texts, despawn_expired))
+        .run();
+}
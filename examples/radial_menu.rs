@@ -0,0 +1,61 @@
+//! Labels around a ring turned toward the cursor with `FaceScreenPoint`.
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_mod_ui_independent_text::*;
+
+const CENTER: Vec2 = Vec2::new(400., 300.);
+const RADIUS: f32 = 180.;
+const ITEMS: [&str; 8] = [
+    "Attack", "Defend", "Magic", "Items", "Talk", "Flee", "Wait", "Equip",
+];
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    let font = asset_loader.load("Topaz-8.ttf");
+    for (index, item) in ITEMS.into_iter().enumerate() {
+        let angle = TAU * index as f32 / ITEMS.len() as f32;
+        let position = CENTER + RADIUS * Vec2::from_angle(angle);
+        commands.spawn((
+            IndependentTextBundle {
+                text: UiText::from_section(
+                    item,
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 24.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                transform: Transform::from_translation(position.extend(0.)),
+                ..Default::default()
+            },
+            FaceScreenPoint(CENTER),
+        ));
+    }
+}
+
+fn follow_cursor(
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut query: Query<&mut FaceScreenPoint>,
+) {
+    let Some(cursor) = window_query
+        .get_single()
+        .ok()
+        .and_then(Window::cursor_position)
+    else {
+        return;
+    };
+    for mut point in &mut query {
+        point.0 = cursor;
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, follow_cursor)
+        .run();
+}
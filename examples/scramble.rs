@@ -0,0 +1,45 @@
+//! Decodes a message with `UiTextScramble`, press space to scramble it again.
+use bevy::prelude::*;
+use bevy_mod_ui_independent_text::*;
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    commands.spawn((
+        IndependentTextBundle {
+            text: UiText::from_section(
+                "INCOMING TRANSMISSION\nsector 7 is compromised",
+                TextStyle {
+                    font: asset_loader.load("Topaz-8.ttf"),
+                    font_size: 32.0,
+                    color: Color::srgb(0.3, 1., 0.4),
+                },
+            ),
+            transform: Transform::from_xyz(400., 300., 0.),
+            ..Default::default()
+        },
+        UiTextScramble::new(12.).with_charset(ScrambleCharset::Uppercase),
+    ));
+}
+
+fn restart(keyboard: Res<ButtonInput<KeyCode>>, mut query: Query<&mut UiTextScramble>) {
+    if keyboard.just_pressed(KeyCode::Space) {
+        for mut scramble in &mut query {
+            scramble.restart();
+        }
+    }
+}
+
+fn log_finished(mut events: EventReader<UiTextScrambleFinished>) {
+    for UiTextScrambleFinished(entity) in events.read() {
+        info!("{entity:?} decoded");
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (restart, log_finished))
+        .run();
+}
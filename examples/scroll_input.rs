@@ -0,0 +1,74 @@
+//! A single line text field that scrolls to keep the end of the text in view. Type to add
+//! characters and press backspace to remove them.
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use bevy::text::BreakLineOn;
+use bevy_mod_ui_independent_text::*;
+
+const FIELD_SIZE: Vec2 = Vec2::new(240., 32.);
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    commands.spawn(NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(400. - 0.5 * FIELD_SIZE.x),
+            top: Val::Px(300. - 0.5 * FIELD_SIZE.y),
+            width: Val::Px(FIELD_SIZE.x),
+            height: Val::Px(FIELD_SIZE.y),
+            ..Default::default()
+        },
+        background_color: Color::srgb(0.15, 0.15, 0.2).into(),
+        ..Default::default()
+    });
+    commands.spawn((
+        IndependentTextBundle {
+            text: UiText(Text {
+                sections: vec![TextSection::new(
+                    "Type something long:",
+                    TextStyle {
+                        font: asset_loader.load("Topaz-8.ttf"),
+                        font_size: 24.,
+                        color: Color::WHITE,
+                    },
+                )],
+                justify: JustifyText::Left,
+                linebreak_behavior: BreakLineOn::NoWrap,
+            }),
+            transform: Transform::from_xyz(400., 300., 100.),
+            bounds: UiTextBounds::new(FIELD_SIZE.x, FIELD_SIZE.y),
+            ..Default::default()
+        },
+        // clamped to show the end of the text
+        HorizontalScroll(f32::MAX),
+    ));
+}
+
+fn type_text(mut events: EventReader<KeyboardInput>, mut text_query: Query<&mut UiText>) {
+    for event in events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        for mut text in &mut text_query {
+            let value = &mut text.sections[0].value;
+            match &event.logical_key {
+                Key::Character(characters) => value.push_str(characters),
+                Key::Space => value.push(' '),
+                Key::Backspace => {
+                    value.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, type_text)
+        .run();
+}
@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+use bevy::sprite::{BorderRect, TextureSlicer};
+use bevy_mod_ui_independent_text::*;
+
+const LINES: [&str; 3] = [
+    "Hello!",
+    "The bubble stretches\nto fit the text.",
+    "Its corners and the tail\nkeep their size however long the line gets.",
+];
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    commands.spawn((
+        IndependentTextBundle {
+            text: UiText::from_section(
+                LINES[0],
+                TextStyle {
+                    font: asset_loader.load("Topaz-8.ttf"),
+                    font_size: 16.0,
+                    color: Color::srgb(0.25, 0.15, 0.1),
+                },
+            ),
+            transform: Transform::from_xyz(400., 300., 100.),
+            ..Default::default()
+        },
+        UiTextImageBackground {
+            image: asset_loader.load("textures/speech_bubble.png"),
+            slicer: TextureSlicer {
+                border: BorderRect {
+                    left: 16.,
+                    right: 16.,
+                    top: 16.,
+                    bottom: 24.,
+                },
+                ..Default::default()
+            },
+            // the bottom border holds the bubble's tail
+            padding: UiRect {
+                left: Val::Px(12.),
+                right: Val::Px(12.),
+                top: Val::Px(12.),
+                bottom: Val::Px(24.),
+            },
+        },
+    ));
+}
+
+fn cycle_lines(time: Res<Time>, mut query: Query<&mut UiText>) {
+    let line = LINES[(time.elapsed_seconds() / 2.) as usize % LINES.len()];
+    for mut text in &mut query {
+        if text.sections[0].value != line {
+            text.sections[0].value = line.to_string();
+        }
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, cycle_lines)
+        .run();
+}
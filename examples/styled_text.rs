@@ -0,0 +1,40 @@
+//! Texts styled from `assets/menu.styles.ron`.
+//!
+//! Run with `--features bevy/file_watcher` and edit the file while the example is running to
+//! see the texts restyled.
+use bevy::prelude::*;
+use bevy_mod_ui_independent_text::*;
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    commands.spawn((
+        IndependentTextBundle {
+            text: UiText::from_section("Main Menu", TextStyle::default()),
+            transform: Transform::from_xyz(400., 150., 100.),
+            ..Default::default()
+        },
+        UiTextStyleRef::new(asset_server.load("menu.styles.ron#title")),
+    ));
+    for (i, item) in ["Continue", "Options", "Quit"].into_iter().enumerate() {
+        commands.spawn((
+            IndependentTextBundle {
+                text: UiText::from_sections([
+                    TextSection::new(item, TextStyle::default()),
+                    TextSection::new(" <", TextStyle::default()),
+                ]),
+                transform: Transform::from_xyz(400., 250. + 50. * i as f32, 100.),
+                ..Default::default()
+            },
+            UiTextStyleRef::new(asset_server.load("menu.styles.ron#item"))
+                .with_section(1, asset_server.load("menu.styles.ron#highlight")),
+        ));
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
@@ -0,0 +1,33 @@
+//! The same text drawn normally and with increasing `SyntheticBold` strengths.
+use bevy::prelude::*;
+use bevy_mod_ui_independent_text::*;
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    let font = asset_loader.load("Topaz-8.ttf");
+    for (i, strength) in [0., 0.5, 1., 2.].into_iter().enumerate() {
+        let mut text = commands.spawn(IndependentTextBundle {
+            text: UiText::from_section(
+                format!("SyntheticBold({strength})"),
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 32.,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(400., 150. + 100. * i as f32, 100.),
+            ..Default::default()
+        });
+        if 0. < strength {
+            text.insert(SyntheticBold(strength));
+        }
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
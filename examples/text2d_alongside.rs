@@ -0,0 +1,60 @@
+//! Bevy's `Text2dBundle`s and `IndependentTextBundle`s drawn side by side, each exactly once.
+use bevy::prelude::*;
+use bevy_mod_ui_independent_text::*;
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    let style = TextStyle {
+        font: asset_loader.load("Topaz-8.ttf"),
+        font_size: 32.0,
+        color: Color::WHITE,
+    };
+    let parent = commands
+        .spawn(Text2dBundle {
+            text: Text::from_section("Text2d", style.clone()),
+            transform: Transform::from_xyz(0., 100., 0.),
+            ..Default::default()
+        })
+        .id();
+    // a UiText child of a Text2d entity, and the other way around
+    commands.entity(parent).with_children(|parent| {
+        parent.spawn(IndependentTextBundle {
+            text: UiText::from_section(
+                "UiText child",
+                TextStyle {
+                    color: Color::srgb(1., 0.8, 0.2),
+                    ..style.clone()
+                },
+            ),
+            transform: Transform::from_xyz(0., -50., 100.),
+            ..Default::default()
+        });
+    });
+    commands
+        .spawn(IndependentTextBundle {
+            text: UiText::from_section("UiText", style.clone()),
+            transform: Transform::from_xyz(0., -50., 100.),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            parent.spawn(Text2dBundle {
+                text: Text::from_section(
+                    "Text2d child",
+                    TextStyle {
+                        color: Color::srgb(0.2, 0.8, 1.),
+                        ..style
+                    },
+                ),
+                transform: Transform::from_xyz(0., -50., 0.),
+                ..Default::default()
+            });
+        });
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
@@ -0,0 +1,69 @@
+//! Two text inputs, the second masked like a password field. Click an input to focus it and
+//! press enter to submit its value.
+use bevy::prelude::*;
+use bevy::text::BreakLineOn;
+use bevy_mod_ui_independent_text::*;
+
+const FIELD_SIZE: Vec2 = Vec2::new(300., 32.);
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    let font = asset_loader.load("Topaz-8.ttf");
+    for (i, input) in [
+        UiTextInput::new("user").with_max_length(16),
+        UiTextInput::default().with_mask('*'),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let y = 250. + 60. * i as f32;
+        commands.spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(400. - 0.5 * FIELD_SIZE.x),
+                top: Val::Px(y - 0.5 * FIELD_SIZE.y),
+                width: Val::Px(FIELD_SIZE.x),
+                height: Val::Px(FIELD_SIZE.y),
+                ..Default::default()
+            },
+            background_color: Color::srgb(0.15, 0.15, 0.2).into(),
+            ..Default::default()
+        });
+        commands.spawn((
+            IndependentTextBundle {
+                text: UiText(Text {
+                    sections: vec![TextSection::new(
+                        "",
+                        TextStyle {
+                            font: font.clone(),
+                            font_size: 24.,
+                            color: Color::WHITE,
+                        },
+                    )],
+                    justify: JustifyText::Left,
+                    linebreak_behavior: BreakLineOn::NoWrap,
+                }),
+                transform: Transform::from_xyz(400., y, 100.),
+                bounds: UiTextBounds::new(FIELD_SIZE.x, FIELD_SIZE.y),
+                ..Default::default()
+            },
+            input,
+            HorizontalScroll(f32::MAX),
+        ));
+    }
+}
+
+fn log_submitted(mut events: EventReader<UiTextSubmitted>) {
+    for event in events.read() {
+        info!("{:?} submitted {:?}", event.entity, event.value);
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, log_submitted)
+        .run();
+}
@@ -0,0 +1,63 @@
+//! A title fading in while it grows from 80% to 100% of its size.
+//!
+//! Bevy 0.14's animation clips can't target components other than `Transform`, so the
+//! values are tweened with `Animatable::interpolate` from a system.
+use bevy::animation::animatable::Animatable;
+use bevy::prelude::*;
+use bevy_mod_ui_independent_text::*;
+
+const DURATION: f32 = 1.5;
+
+#[derive(Component)]
+struct Intro;
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    commands.spawn((
+        IndependentTextBundle {
+            text: UiText::from_section(
+                "Chapter One",
+                TextStyle {
+                    font: asset_loader.load("Topaz-8.ttf"),
+                    font_size: 64.,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(400., 300., 100.),
+            ..Default::default()
+        },
+        UiTextGroupOpacity(0.),
+        UiTextFontScale(0.8),
+        Intro,
+    ));
+}
+
+fn play_intro(
+    time: Res<Time>,
+    mut query: Query<(&mut UiTextGroupOpacity, &mut UiTextFontScale), With<Intro>>,
+) {
+    let t = (time.elapsed_seconds() / DURATION).min(1.);
+    // ease out
+    let t = 1. - (1. - t) * (1. - t);
+    for (mut opacity, mut font_scale) in &mut query {
+        opacity.set_if_neq(UiTextGroupOpacity::interpolate(
+            &UiTextGroupOpacity(0.),
+            &UiTextGroupOpacity(1.),
+            t,
+        ));
+        font_scale.set_if_neq(UiTextFontScale::interpolate(
+            &UiTextFontScale(0.8),
+            &UiTextFontScale(1.),
+            t,
+        ));
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, play_intro)
+        .run();
+}
@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+use bevy_mod_ui_independent_text::*;
+
+#[derive(Component)]
+struct Description(&'static str);
+
+#[derive(Component)]
+struct Tooltip;
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                justify_content: JustifyContent::SpaceEvenly,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .with_children(|root| {
+            for (color, description) in [
+                (
+                    Color::srgb(0.8, 0.2, 0.2),
+                    "Fire\nDeals burn damage over time",
+                ),
+                (Color::srgb(0.2, 0.4, 0.9), "Ice\nSlows enemies"),
+                (
+                    Color::srgb(0.9, 0.8, 0.2),
+                    "Lightning\nChains between nearby enemies",
+                ),
+            ] {
+                root.spawn((
+                    ButtonBundle {
+                        style: Style {
+                            width: Val::Px(120.),
+                            height: Val::Px(120.),
+                            ..Default::default()
+                        },
+                        background_color: color.into(),
+                        ..Default::default()
+                    },
+                    Description(description),
+                ));
+            }
+        });
+    commands.spawn((
+        IndependentTextBundle {
+            text: UiText::from_section(
+                "",
+                TextStyle {
+                    font: asset_loader.load("Topaz-8.ttf"),
+                    font_size: 16.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(0., 0., 100.),
+            visibility: Visibility::Hidden,
+            ..Default::default()
+        },
+        UiTextTooltip::default(),
+        Tooltip,
+    ));
+}
+
+fn update_tooltip(
+    button_query: Query<(&Interaction, &Description)>,
+    mut tooltip_query: Query<(&mut UiText, &mut Visibility), With<Tooltip>>,
+) {
+    let hovered = button_query
+        .iter()
+        .find(|(interaction, _)| **interaction != Interaction::None);
+    for (mut text, mut visibility) in &mut tooltip_query {
+        match hovered {
+            Some((_, description)) => {
+                if text.sections[0].value != description.0 {
+                    text.sections[0].value = description.0.to_string();
+                }
+                *visibility = Visibility::Inherited;
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, update_tooltip)
+        .run();
+}
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::window::{WindowRef, WindowResolution};
+use bevy_mod_ui_independent_text::*;
+
+fn label(value: &str, font: Handle<Font>) -> UiText {
+    UiText::from_section(
+        value,
+        TextStyle {
+            font,
+            font_size: 32.0,
+            color: Color::WHITE,
+        },
+    )
+}
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    let font = asset_loader.load("Topaz-8.ttf");
+
+    let first_camera = commands.spawn(Camera2dBundle::default()).id();
+    commands.spawn((
+        IndependentTextBundle {
+            text: label("First window", font.clone()),
+            transform: Transform::from_xyz(300., 200., 0.),
+            ..Default::default()
+        },
+        TargetCamera(first_camera),
+    ));
+
+    let second_window = commands
+        .spawn(Window {
+            title: "Second window".to_string(),
+            // each window's texts are laid out with that window's scale factor
+            resolution: WindowResolution::new(600., 400.).with_scale_factor_override(2.),
+            ..Default::default()
+        })
+        .id();
+    let second_camera = commands
+        .spawn(Camera2dBundle {
+            camera: Camera {
+                target: RenderTarget::Window(WindowRef::Entity(second_window)),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .id();
+    commands.spawn((
+        IndependentTextBundle {
+            text: label("Second window", font),
+            transform: Transform::from_xyz(300., 200., 0.),
+            ..Default::default()
+        },
+        TargetCamera(second_camera),
+    ));
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
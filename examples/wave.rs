@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+use bevy_mod_ui_independent_text::*;
+
+fn setup(mut commands: Commands, asset_loader: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    commands.spawn((
+        IndependentTextBundle {
+            text: UiText::from_section(
+                "Welcome, traveller, to the wobbling inn!",
+                TextStyle {
+                    font: asset_loader.load("Topaz-8.ttf"),
+                    font_size: 32.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(400., 300., 0.),
+            ..Default::default()
+        },
+        WaveText {
+            amplitude: 6.,
+            frequency: 0.08,
+            speed: 0.75,
+        },
+    ));
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(IndependentTextPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
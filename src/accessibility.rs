@@ -0,0 +1,112 @@
+use bevy::a11y::accesskit::{NodeBuilder, Rect, Role};
+use bevy::a11y::AccessibilityNode;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+use crate::{
+    drawn_global_transform, text_transform, ScreenOffset, UiText, UiTextComputedSize,
+    UiTextKeepUpright,
+};
+
+/// Keeps a [`UiText`] out of the accessibility tree, for texts that are purely decorative.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextA11yIgnore;
+
+/// Gives every visible [`UiText`] an [`AccessibilityNode`], so screen readers can read it.
+///
+/// The node's role is [`Role::StaticText`], AccessKit's role for labels, its name is the text's
+/// sections joined together, and its bounds are the laid out text's bounding rect in window
+/// coordinates. The node is removed while the text is hidden or has a [`UiTextA11yIgnore`].
+#[allow(clippy::type_complexity)]
+pub fn update_ui_text_accessibility(
+    mut commands: Commands,
+    mut text_query: Query<(
+        Entity,
+        Ref<UiText>,
+        &ViewVisibility,
+        Ref<GlobalTransform>,
+        Option<Ref<UiTextComputedSize>>,
+        Option<Ref<ScreenOffset>>,
+        Option<Ref<Anchor>>,
+        Option<(&UiTextKeepUpright, &Transform)>,
+        Has<UiTextA11yIgnore>,
+        Option<&mut AccessibilityNode>,
+    )>,
+) {
+    for (
+        entity,
+        text,
+        view_visibility,
+        global_transform,
+        maybe_size,
+        maybe_screen_offset,
+        maybe_anchor,
+        maybe_keep_upright,
+        ignore,
+        maybe_node,
+    ) in &mut text_query
+    {
+        if ignore || !view_visibility.get() {
+            if maybe_node.is_some() {
+                commands.entity(entity).remove::<AccessibilityNode>();
+            }
+            continue;
+        }
+
+        let name = || {
+            text.sections
+                .iter()
+                .map(|section| section.value.as_str())
+                .collect::<String>()
+                .into_boxed_str()
+        };
+        let bounds = || {
+            let size = maybe_size.as_deref().map_or(Vec2::ZERO, |size| size.size);
+            let transform = text_transform(
+                &drawn_global_transform(&global_transform, maybe_keep_upright),
+                size,
+                maybe_anchor.as_deref(),
+                1.,
+                maybe_screen_offset
+                    .as_deref()
+                    .map_or(Vec2::ZERO, |offset| offset.0),
+                true,
+            );
+            let corners = [
+                Vec2::ZERO,
+                Vec2::new(size.x, 0.),
+                size,
+                Vec2::new(0., size.y),
+            ]
+            .map(|corner| transform.transform_point3(corner.extend(0.)).truncate());
+            let min = corners.into_iter().reduce(Vec2::min).unwrap_or_default();
+            let max = corners.into_iter().reduce(Vec2::max).unwrap_or_default();
+            Rect::new(min.x.into(), min.y.into(), max.x.into(), max.y.into())
+        };
+
+        match maybe_node {
+            Some(mut node) => {
+                if text.is_changed() {
+                    node.set_name(name());
+                }
+                if global_transform.is_changed()
+                    || maybe_size.as_ref().is_some_and(Ref::is_changed)
+                    || maybe_screen_offset.as_ref().is_some_and(Ref::is_changed)
+                    || maybe_anchor.as_ref().is_some_and(Ref::is_changed)
+                {
+                    node.set_bounds(bounds());
+                }
+            }
+            None => {
+                let mut node = NodeBuilder::new(Role::StaticText);
+                node.set_name(name());
+                node.set_bounds(bounds());
+                commands
+                    .entity(entity)
+                    .try_insert(AccessibilityNode::from(node));
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use bevy::animation::animatable::{Animatable, BlendInput};
+use bevy::prelude::*;
+
+use crate::{TextTint, UiTextAlpha, UiTextFontScale, UiTextGroupOpacity};
+
+// Bevy 0.14's `AnimationClip`s can only key transforms and morph weights, so these are for
+// blending and tweening the values from user systems until clips can target components.
+
+impl Animatable for UiTextFontScale {
+    fn interpolate(a: &Self, b: &Self, time: f32) -> Self {
+        Self(f32::interpolate(&a.0, &b.0, time))
+    }
+
+    fn blend(inputs: impl Iterator<Item = BlendInput<Self>>) -> Self {
+        Self(f32::blend(inputs.map(|input| BlendInput {
+            weight: input.weight,
+            value: input.value.0,
+            additive: input.additive,
+        })))
+    }
+}
+
+impl Animatable for UiTextGroupOpacity {
+    fn interpolate(a: &Self, b: &Self, time: f32) -> Self {
+        Self(f32::interpolate(&a.0, &b.0, time))
+    }
+
+    fn blend(inputs: impl Iterator<Item = BlendInput<Self>>) -> Self {
+        Self(f32::blend(inputs.map(|input| BlendInput {
+            weight: input.weight,
+            value: input.value.0,
+            additive: input.additive,
+        })))
+    }
+}
+
+impl Animatable for UiTextAlpha {
+    fn interpolate(a: &Self, b: &Self, time: f32) -> Self {
+        Self(f32::interpolate(&a.0, &b.0, time))
+    }
+
+    fn blend(inputs: impl Iterator<Item = BlendInput<Self>>) -> Self {
+        Self(f32::blend(inputs.map(|input| BlendInput {
+            weight: input.weight,
+            value: input.value.0,
+            additive: input.additive,
+        })))
+    }
+}
+
+impl Animatable for TextTint {
+    fn interpolate(a: &Self, b: &Self, time: f32) -> Self {
+        Self(LinearRgba::interpolate(&a.0.into(), &b.0.into(), time).into())
+    }
+
+    fn blend(inputs: impl Iterator<Item = BlendInput<Self>>) -> Self {
+        Self(
+            LinearRgba::blend(inputs.map(|input| BlendInput {
+                weight: input.weight,
+                value: input.value.0.into(),
+                additive: input.additive,
+            }))
+            .into(),
+        )
+    }
+}
@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+use bevy::text::{TextError, TextLayoutInfo};
+
+/// What to do when a glyph of a [`UiText`](crate::UiText) can't be added to a font atlas,
+/// usually because the font size is too large for a glyph to fit into an atlas.
+///
+/// A [`UiTextError`] is sent whichever policy is chosen, before panicking with
+/// [`Panic`](Self::Panic).
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Resource, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UiTextAtlasPolicy {
+    /// Logs a warning and keeps the text's previous layout.
+    #[default]
+    WarnAndSkip,
+    /// Lays out the text again with the font size of every section clamped to `max`,
+    /// then warns and keeps the previous layout if that fails too.
+    ClampFontSize { max: f32 },
+    /// Panics, for apps that want to fail fast.
+    Panic,
+}
+
+/// Sent when the layout of a text fails with an error other than its fonts still loading.
+#[derive(Event, Debug)]
+pub struct UiTextError {
+    pub entity: Entity,
+    pub error: TextError,
+}
+
+impl UiTextAtlasPolicy {
+    /// Lays out the sections with `queue_text`, applying the policy to glyphs that don't fit
+    /// into a font atlas. `on_error` is called with every failure.
+    pub(crate) fn queue_text(
+        self,
+        entity: Entity,
+        sections: &[TextSection],
+        mut queue_text: impl FnMut(&[TextSection]) -> Result<TextLayoutInfo, TextError>,
+        mut on_error: impl FnMut(UiTextError),
+    ) -> Result<TextLayoutInfo, TextError> {
+        let mut clamped_sections = None;
+        loop {
+            let result = queue_text(clamped_sections.as_deref().unwrap_or(sections));
+            let Err(TextError::FailedToAddGlyph(glyph_id)) = result else {
+                return result;
+            };
+            let error = TextError::FailedToAddGlyph(glyph_id);
+            let message = format!("Failed to lay out the text of {entity:?}: {error}.");
+            on_error(UiTextError { entity, error });
+            match self {
+                Self::ClampFontSize { max } if clamped_sections.is_none() => {
+                    clamped_sections = Some(clamp_font_sizes(sections, max));
+                }
+                Self::Panic => panic!("{message}"),
+                _ => {
+                    warn!("{message} Keeping its previous layout.");
+                    return result;
+                }
+            }
+        }
+    }
+}
+
+fn clamp_font_sizes(sections: &[TextSection], max: f32) -> Vec<TextSection> {
+    sections
+        .iter()
+        .map(|section| {
+            let mut section = section.clone();
+            section.style.font_size = section.style.font_size.min(max);
+            section
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Lays out the sections like an atlas only fitting glyphs up to 64 pixels, returning the
+    /// font sizes of every attempt, the result and the number of errors.
+    fn lay_out(policy: UiTextAtlasPolicy, font_size: f32) -> (Vec<f32>, bool, usize) {
+        let sections = [TextSection::new(
+            "A",
+            TextStyle {
+                font_size,
+                ..default()
+            },
+        )];
+        let mut attempts = Vec::new();
+        let mut errors = 0;
+        let result = policy.queue_text(
+            Entity::PLACEHOLDER,
+            &sections,
+            |sections| {
+                let font_size = sections[0].style.font_size;
+                attempts.push(font_size);
+                if 64. < font_size {
+                    Err(TextError::FailedToAddGlyph(ab_glyph::GlyphId(1)))
+                } else {
+                    Ok(TextLayoutInfo::default())
+                }
+            },
+            |error| {
+                assert_eq!(error.entity, Entity::PLACEHOLDER);
+                errors += 1;
+            },
+        );
+        (attempts, result.is_ok(), errors)
+    }
+
+    #[test]
+    fn fitting_glyphs_are_laid_out_once() {
+        for policy in [
+            UiTextAtlasPolicy::WarnAndSkip,
+            UiTextAtlasPolicy::ClampFontSize { max: 32. },
+            UiTextAtlasPolicy::Panic,
+        ] {
+            assert_eq!(lay_out(policy, 16.), (vec![16.], true, 0));
+        }
+    }
+
+    #[test]
+    fn warn_and_skip_gives_up_after_one_error() {
+        assert_eq!(
+            lay_out(UiTextAtlasPolicy::WarnAndSkip, 100.),
+            (vec![100.], false, 1)
+        );
+    }
+
+    #[test]
+    fn clamp_font_size_retries_at_the_clamped_size() {
+        assert_eq!(
+            lay_out(UiTextAtlasPolicy::ClampFontSize { max: 48. }, 100.),
+            (vec![100., 48.], true, 1)
+        );
+    }
+
+    #[test]
+    fn clamp_font_size_retries_only_once() {
+        assert_eq!(
+            lay_out(UiTextAtlasPolicy::ClampFontSize { max: 80. }, 100.),
+            (vec![100., 80.], false, 2)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to lay out the text")]
+    fn panic_panics_on_the_first_error() {
+        lay_out(UiTextAtlasPolicy::Panic, 100.);
+    }
+}
@@ -0,0 +1,295 @@
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy::sprite::TextureSlice;
+use bevy::sprite::TextureSlicer;
+#[cfg(feature = "render")]
+use bevy::ui::{ExtractedUiNode, NodeType};
+
+#[cfg(feature = "render")]
+use crate::UiTextGlyphs;
+
+/// An image drawn behind a [`UiText`](crate::UiText), nine-sliced to fit around the laid out
+/// text and its `padding`.
+///
+/// The background follows the text's size, transform and depth. An image whose slicer borders
+/// are all zero is stretched over the whole background instead, e.g. for speech bubble or
+/// tooltip frames that stretch with the text.
+#[doc(alias = "UiTextFrame")]
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct UiTextImageBackground {
+    pub image: Handle<Image>,
+    pub slicer: TextureSlicer,
+    /// Space between the text and the edges of the image, in the same units as the text's
+    /// [`Transform`]. `Val::Percent` is relative to the width of the text.
+    pub padding: UiRect,
+}
+
+impl UiTextImageBackground {
+    /// A frame of `image` nine-sliced by `slicer`.
+    pub fn new(image: Handle<Image>, slicer: TextureSlicer) -> Self {
+        Self {
+            image,
+            slicer,
+            padding: UiRect::default(),
+        }
+    }
+
+    pub fn with_padding(mut self, padding: UiRect) -> Self {
+        self.padding = padding;
+        self
+    }
+}
+
+/// Converts a text's image background into [`ExtractedUiNode`]s, one per slice.
+///
+/// Used by [`extract_text_sprite`](crate::extract_text_sprite), the nodes must be drawn before
+/// the text's glyph nodes.
+#[cfg(feature = "render")]
+pub fn ui_text_image_background_nodes(
+    background: &UiTextImageBackground,
+    image_size: Vec2,
+    glyphs: UiTextGlyphs,
+    nodes: &mut impl Extend<ExtractedUiNode>,
+) {
+    if image_size.cmple(Vec2::ZERO).any() {
+        return;
+    }
+    let (transform, _) = glyphs.transform();
+    let plate = padded_text_rect(&background.padding, glyphs.visible_size());
+    let size = plate.size();
+    let center_transform = transform * Mat4::from_translation(plate.center().extend(0.));
+
+    let image_rect = Rect::from_corners(Vec2::ZERO, image_size);
+    let border = &background.slicer.border;
+    let slices = if [border.left, border.right, border.top, border.bottom] == [0.; 4] {
+        vec![TextureSlice {
+            texture_rect: image_rect,
+            draw_size: size,
+            offset: Vec2::ZERO,
+        }]
+    } else {
+        background.slicer.compute_slices(image_rect, Some(size))
+    };
+
+    let stack_index = glyphs.stack_index;
+    nodes.extend(slices.into_iter().map(|slice| {
+        // slice offsets point up, UI coordinates point down
+        let offset = slice.offset * Vec2::new(1., -1.);
+        let scale = slice.draw_size / slice.texture_rect.size();
+        let mut rect = slice.texture_rect;
+        rect.min *= scale;
+        rect.max *= scale;
+        ExtractedUiNode {
+            stack_index,
+            transform: center_transform * Mat4::from_translation(offset.extend(0.)),
+            color: LinearRgba::WHITE.with_alpha(glyphs.opacity),
+            rect,
+            image: background.image.id(),
+            atlas_size: Some(image_size * scale),
+            clip: None,
+            flip_x: false,
+            flip_y: false,
+            camera_entity: glyphs.camera_entity,
+            border: [0.; 4],
+            border_radius: [0.; 4],
+            node_type: NodeType::Rect,
+        }
+    }));
+}
+
+/// The rect around a text of `text_size` grown by `padding`, in the text's local space with the
+/// origin at its top left.
+#[cfg(feature = "render")]
+fn padded_text_rect(padding: &UiRect, text_size: Vec2) -> Rect {
+    let resolve = |val: Val| val.resolve(text_size.x, Vec2::ZERO).unwrap_or(0.);
+    let min = -Vec2::new(resolve(padding.left), resolve(padding.top));
+    let max = text_size + Vec2::new(resolve(padding.right), resolve(padding.bottom));
+    Rect::from_corners(min, max.max(min))
+}
+
+/// Direction a two color gradient runs in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GradientDirection {
+    /// From the top to the bottom.
+    #[default]
+    Vertical,
+    /// From the left to the right.
+    Horizontal,
+}
+
+/// How a [`UiTextBackground`] is filled.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BackgroundFill {
+    Color(Color),
+    /// A gradient from `start` to `end`, interpolated in linear color space.
+    Gradient {
+        direction: GradientDirection,
+        start: Color,
+        end: Color,
+    },
+}
+
+impl Default for BackgroundFill {
+    fn default() -> Self {
+        Self::Color(Color::srgba(0., 0., 0., 0.6))
+    }
+}
+
+/// A plate drawn behind a [`UiText`](crate::UiText), sized to the laid out text and its
+/// `padding`, e.g. for callouts and speech bubbles.
+///
+/// The plate follows the text's size, transform and depth, and is drawn behind a
+/// [`UiTextImageBackground`]. A gradient is drawn as bands of interpolated colors, with the
+/// rounded ends of the plate as flat bands of their own.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextBackground {
+    pub fill: BackgroundFill,
+    /// Space between the text and the edges of the plate, in the same units as the text's
+    /// [`Transform`]. `Val::Percent` is relative to the width of the text.
+    pub padding: UiRect,
+    /// Radius of the plate's corners, in the same units as the text's [`Transform`].
+    pub border_radius: f32,
+}
+
+impl UiTextBackground {
+    /// A plate of a flat `color` with `padding` around the text.
+    pub fn new(color: Color, padding: UiRect) -> Self {
+        Self {
+            fill: BackgroundFill::Color(color),
+            padding,
+            ..Default::default()
+        }
+    }
+
+    pub fn color(color: Color) -> Self {
+        Self {
+            fill: BackgroundFill::Color(color),
+            ..Default::default()
+        }
+    }
+
+    pub fn gradient(direction: GradientDirection, start: Color, end: Color) -> Self {
+        Self {
+            fill: BackgroundFill::Gradient {
+                direction,
+                start,
+                end,
+            },
+            ..Default::default()
+        }
+    }
+
+    pub fn with_padding(mut self, padding: UiRect) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    pub fn with_border_radius(mut self, border_radius: f32) -> Self {
+        self.border_radius = border_radius;
+        self
+    }
+}
+
+/// Number of bands a gradient is drawn with between the plate's rounded ends.
+#[cfg(feature = "render")]
+const GRADIENT_BANDS: usize = 16;
+
+/// Converts a text's background plate into [`ExtractedUiNode`]s.
+///
+/// Used by [`extract_text_sprite`](crate::extract_text_sprite), the nodes must be drawn before
+/// the text's other nodes.
+#[cfg(feature = "render")]
+pub fn ui_text_background_nodes(
+    background: &UiTextBackground,
+    glyphs: UiTextGlyphs,
+    nodes: &mut impl Extend<ExtractedUiNode>,
+) {
+    let (transform, _) = glyphs.transform();
+    let plate = padded_text_rect(&background.padding, glyphs.visible_size());
+    if plate.is_empty() {
+        return;
+    }
+    let radius = background
+        .border_radius
+        .clamp(0., 0.5 * plate.size().min_element());
+    let node = |rect: Rect, mut color: LinearRgba, border_radius: [f32; 4]| {
+        color.alpha *= glyphs.opacity;
+        ExtractedUiNode {
+            stack_index: glyphs.stack_index,
+            transform: transform * Mat4::from_translation(rect.center().extend(0.)),
+            color,
+            rect: Rect::from_corners(Vec2::ZERO, rect.size()),
+            image: AssetId::default(),
+            atlas_size: None,
+            clip: None,
+            flip_x: false,
+            flip_y: false,
+            camera_entity: glyphs.camera_entity,
+            border: [0.; 4],
+            border_radius,
+            node_type: NodeType::Rect,
+        }
+    };
+
+    let (direction, start, end) = match background.fill {
+        BackgroundFill::Color(color) => {
+            nodes.extend([node(plate, color.into(), [radius; 4])]);
+            return;
+        }
+        BackgroundFill::Gradient {
+            direction,
+            start,
+            end,
+        } => (direction, LinearRgba::from(start), LinearRgba::from(end)),
+    };
+    // bands along the gradient's axis, with `t` running from 0 at the plate's start to 1 at
+    // its end, the first and last bands hold the rounded corners
+    let axis = match direction {
+        GradientDirection::Vertical => 1,
+        GradientDirection::Horizontal => 0,
+    };
+    let length = plate.size()[axis];
+    let mut cuts = vec![0.];
+    if 0. < radius {
+        // the rounded ends meet in the middle of a plate as long as its radius
+        cuts.push((radius / length).min(0.5));
+    }
+    let inner_start = cuts[cuts.len() - 1];
+    let inner_end = 1. - inner_start;
+    cuts.extend(
+        (1..=GRADIENT_BANDS).map(|band| {
+            inner_start + (inner_end - inner_start) * band as f32 / GRADIENT_BANDS as f32
+        }),
+    );
+    if 0. < radius {
+        cuts.push(1.);
+    }
+    let last = cuts.len() - 2;
+    nodes.extend(
+        cuts.windows(2)
+            .enumerate()
+            // the inner bands are empty between ends that meet
+            .filter(|(_, cut)| cut[0] < cut[1])
+            .map(|(index, cut)| {
+                let mut rect = plate;
+                rect.min[axis] = plate.min[axis] + cut[0] * length;
+                rect.max[axis] = plate.min[axis] + cut[1] * length;
+                let color = start.mix(&end, 0.5 * (cut[0] + cut[1]));
+                // top left, top right, bottom right, bottom left
+                let border_radius = match (axis, index == 0, index == last) {
+                    (_, true, true) => [radius; 4],
+                    (1, true, false) => [radius, radius, 0., 0.],
+                    (1, false, true) => [0., 0., radius, radius],
+                    (0, true, false) => [radius, 0., 0., radius],
+                    (0, false, true) => [0., radius, radius, 0.],
+                    _ => [0.; 4],
+                };
+                node(rect, color, border_radius)
+            }),
+    );
+}
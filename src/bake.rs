@@ -0,0 +1,117 @@
+use bevy::prelude::*;
+use bevy::text::TextLayoutInfo;
+#[cfg(feature = "render")]
+use bevy::ui::{ExtractedUiNode, NodeType};
+
+use crate::{rasterize_text_layout, UiText};
+#[cfg(feature = "render")]
+use crate::{TextTint, UiTextGlyphs};
+
+/// Draws a [`UiText`] from a single image of its glyphs instead of a node per glyph, for static
+/// labels like the region names of a world map.
+///
+/// After each layout the glyphs are composited on the CPU into a [`UiTextBakedImage`], sized in
+/// the physical pixels the text was laid out at so it stays as sharp as the glyphs. Changing the
+/// text lays it out and bakes it again, so baking only pays off for texts that rarely change.
+///
+/// Each baked text owns an RGBA image of its own, four bytes per physical pixel in both main and
+/// render world memory, where unbaked texts share their glyph atlases. Many small labels are
+/// cheap, long paragraphs or large font sizes aren't. Effects applied to the glyphs as they're
+/// extracted aren't drawn for baked texts: [`WaveText`](crate::WaveText),
+/// [`TextSelection`](crate::TextSelection), [`UiTextCaret`](crate::UiTextCaret),
+/// [`HorizontalScroll`](crate::HorizontalScroll), synthetic bold and italics, and the rotated
+/// glyphs of vertical texts. [`TextTint`] and opacity still apply to the whole image.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextBake;
+
+/// The image a [`UiTextBake`] text is drawn from, written after each layout of the text.
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct UiTextBakedImage {
+    pub image: Handle<Image>,
+    /// Size of the image in physical pixels.
+    pub size: UVec2,
+}
+
+#[allow(clippy::type_complexity)]
+pub fn bake_ui_texts(
+    mut commands: Commands,
+    texture_atlases: Res<Assets<TextureAtlasLayout>>,
+    mut images: ResMut<Assets<Image>>,
+    mut removed_bakes: RemovedComponents<UiTextBake>,
+    mut text_query: Query<
+        (
+            Entity,
+            Ref<UiText>,
+            Ref<TextLayoutInfo>,
+            Option<&mut UiTextBakedImage>,
+        ),
+        With<UiTextBake>,
+    >,
+) {
+    for entity in removed_bakes.read() {
+        // dropping the handle frees the image
+        if let Some(mut entity_commands) = commands.get_entity(entity) {
+            entity_commands.remove::<UiTextBakedImage>();
+        }
+    }
+
+    for (entity, text, layout, maybe_baked) in &mut text_query {
+        let is_baked = maybe_baked.is_some();
+        if is_baked && !text.is_changed() && !layout.is_changed() {
+            continue;
+        }
+        // not laid out yet, e.g. while the font is loading
+        if layout.glyphs.is_empty() {
+            continue;
+        }
+        let image = rasterize_text_layout(&text, &layout, &texture_atlases, &images);
+        let size = image.size();
+        match maybe_baked {
+            // replaced in place so the handle stays the same
+            Some(mut baked) => {
+                images.insert(&baked.image, image);
+                baked.size = size;
+            }
+            None => {
+                commands.entity(entity).insert(UiTextBakedImage {
+                    image: images.add(image),
+                    size,
+                });
+            }
+        }
+    }
+}
+
+/// Converts a baked text's image into a single [`ExtractedUiNode`].
+///
+/// Used by [`extract_text_sprite`](crate::extract_text_sprite) in place of
+/// [`ui_text_glyph_nodes`](crate::ui_text_glyph_nodes).
+#[cfg(feature = "render")]
+pub fn ui_text_baked_image_node(
+    baked: &UiTextBakedImage,
+    glyphs: UiTextGlyphs,
+    nodes: &mut impl Extend<ExtractedUiNode>,
+) {
+    let (transform, inverse_scale_factor) = glyphs.transform();
+    let size = baked.size.as_vec2() * inverse_scale_factor;
+    let mut color = TextTint::apply(glyphs.tint, LinearRgba::WHITE);
+    color.alpha *= glyphs.opacity;
+    nodes.extend([ExtractedUiNode {
+        stack_index: glyphs.stack_index,
+        transform: transform * Mat4::from_translation((0.5 * size).extend(0.)),
+        color,
+        rect: Rect::from_corners(Vec2::ZERO, size),
+        image: baked.image.id(),
+        atlas_size: None,
+        clip: glyphs.clip(),
+        flip_x: false,
+        flip_y: false,
+        camera_entity: glyphs.camera_entity,
+        border: [0.; 4],
+        border_radius: [0.; 4],
+        node_type: NodeType::Rect,
+    }]);
+}
@@ -0,0 +1,137 @@
+use bevy::prelude::*;
+use bevy::text::TextLayoutInfo;
+use unicode_bidi::{BidiInfo, Level};
+
+use crate::lines::glyph_lines;
+
+/// Base direction of the paragraphs of a [`UiText`](crate::UiText).
+///
+/// Right-to-left runs are reordered for display after layout using the Unicode bidi algorithm,
+/// whatever the base direction. In right-to-left paragraphs `JustifyText::Left` aligns lines to
+/// their start on the right, and `JustifyText::Right` to their end on the left.
+///
+/// Glyphs aren't shaped, so scripts with contextual forms like Arabic are drawn with the forms
+/// provided by the font for each isolated character.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UiTextDirection {
+    /// Detected from the first strong directional character of the text.
+    #[default]
+    Auto,
+    Ltr,
+    Rtl,
+}
+
+impl UiTextDirection {
+    fn default_level(self) -> Option<Level> {
+        match self {
+            UiTextDirection::Auto => None,
+            UiTextDirection::Ltr => Some(Level::ltr()),
+            UiTextDirection::Rtl => Some(Level::rtl()),
+        }
+    }
+
+    /// Whether the first paragraph of the sections is laid out right to left.
+    pub(crate) fn is_rtl(self, sections: &[TextSection]) -> bool {
+        match self {
+            UiTextDirection::Ltr => false,
+            UiTextDirection::Rtl => true,
+            UiTextDirection::Auto => {
+                let (text, _) = joined_text(sections);
+                !text.is_ascii()
+                    && BidiInfo::new(&text, None)
+                        .paragraphs
+                        .first()
+                        .is_some_and(|paragraph| paragraph.level.is_rtl())
+            }
+        }
+    }
+
+    /// Swaps left and right justification for right-to-left text.
+    pub(crate) fn justify(self, sections: &[TextSection], justify: JustifyText) -> JustifyText {
+        if !self.is_rtl(sections) {
+            return justify;
+        }
+        match justify {
+            JustifyText::Left => JustifyText::Right,
+            JustifyText::Right => JustifyText::Left,
+            justify => justify,
+        }
+    }
+}
+
+/// The text of all sections joined together and the byte offset of each section in it.
+pub(crate) fn joined_text(sections: &[TextSection]) -> (String, Vec<usize>) {
+    let mut text = String::new();
+    let mut offsets = Vec::with_capacity(sections.len());
+    for section in sections {
+        offsets.push(text.len());
+        text.push_str(&section.value);
+    }
+    (text, offsets)
+}
+
+/// Reorders the glyphs of each line from logical into visual order.
+///
+/// The text pipeline lays out every line left to right in logical order. Each reversal of rule
+/// L2 of the bidi algorithm then mirrors the positions of the reversed glyphs within the span
+/// they cover, which keeps the spacing between them. The glyphs of each line are sorted by
+/// position afterwards, so lines can still be split with [`glyph_lines`].
+pub(crate) fn reorder_bidi_glyphs(
+    layout: &mut TextLayoutInfo,
+    sections: &[TextSection],
+    direction: UiTextDirection,
+) {
+    let (text, section_offsets) = joined_text(sections);
+    if direction != UiTextDirection::Rtl && text.is_ascii() {
+        return;
+    }
+    let bidi_info = BidiInfo::new(&text, direction.default_level());
+    if !bidi_info.has_rtl() {
+        return;
+    }
+    for line in glyph_lines(&layout.glyphs) {
+        let glyphs = &mut layout.glyphs[line];
+        let levels: Vec<u8> = glyphs
+            .iter()
+            .map(|glyph| {
+                bidi_info.levels[section_offsets[glyph.section_index] + glyph.byte_index].number()
+            })
+            .collect();
+        let Some(lowest_odd) = levels.iter().copied().filter(|level| level % 2 == 1).min() else {
+            continue;
+        };
+        let highest = levels.iter().copied().max().unwrap_or(0);
+
+        // indices of the glyphs in visual order
+        let mut order: Vec<usize> = (0..glyphs.len()).collect();
+        for level in (lowest_odd..=highest).rev() {
+            let mut start = 0;
+            while start < order.len() {
+                if levels[order[start]] < level {
+                    start += 1;
+                    continue;
+                }
+                let mut end = start;
+                while end < order.len() && levels[order[end]] >= level {
+                    end += 1;
+                }
+                let run = &mut order[start..end];
+                let (min, max) = run.iter().fold((f32::MAX, f32::MIN), |(min, max), &index| {
+                    let glyph = &glyphs[index];
+                    (
+                        min.min(glyph.position.x - 0.5 * glyph.size.x),
+                        max.max(glyph.position.x + 0.5 * glyph.size.x),
+                    )
+                });
+                for &index in run.iter() {
+                    glyphs[index].position.x = min + max - glyphs[index].position.x;
+                }
+                run.reverse();
+                start = end;
+            }
+        }
+        glyphs.sort_by(|a, b| a.position.x.total_cmp(&b.position.x));
+    }
+}
@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+/// Blinks a [`UiText`](crate::UiText) on and off, e.g. for "PRESS START" prompts.
+///
+/// Every `period` the text is drawn for the first `duty_cycle` fraction of the period and hidden
+/// for the rest, by skipping it during extraction, together with its backgrounds and caret.
+/// The blinking follows the [`UiTextTimeSource`](crate::UiTextTimeSource), so by default it stops
+/// while virtual time is paused. The text stays visible to Bevy, e.g. to its visibility checks
+/// and accessibility.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextBlink {
+    pub period: Duration,
+    /// Fraction of each period the text is shown for, from 0 to 1.
+    pub duty_cycle: f32,
+}
+
+impl Default for UiTextBlink {
+    fn default() -> Self {
+        Self {
+            period: Duration::from_secs(1),
+            duty_cycle: 0.5,
+        }
+    }
+}
+
+impl UiTextBlink {
+    pub fn new(period: Duration, duty_cycle: f32) -> Self {
+        Self { period, duty_cycle }
+    }
+
+    /// Whether the text is shown at `time` seconds.
+    pub fn is_on(&self, time: f32) -> bool {
+        let period = self.period.as_secs_f32();
+        if period <= 0. {
+            return true;
+        }
+        (time / period).fract() < self.duty_cycle
+    }
+}
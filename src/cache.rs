@@ -0,0 +1,179 @@
+use bevy::prelude::*;
+use bevy::text::{BreakLineOn, Font, TextLayoutInfo};
+use bevy::utils::HashMap;
+use bevy::window::WindowScaleFactorChanged;
+
+/// Reuses the layouts of texts with identical sections, bounds and scale factors, e.g. hundreds
+/// of identical damage numbers, instead of laying out each one.
+///
+/// Opt in by inserting the resource. A hit clones the cached [`TextLayoutInfo`] from before the
+/// crate's own post-processing, so per entity components like [`TabStops`](crate::TabStops)
+/// still apply. At most `capacity` layouts are kept, the least recently used is dropped first.
+///
+/// Cached layouts hold strong handles to their fonts and glyph atlases, which keeps the atlas
+/// glyphs they refer to alive. The cache forgets the layouts of a font when it's modified or
+/// removed, and every layout when a window's scale factor changes.
+#[derive(Resource, Debug)]
+pub struct UiTextLayoutCache {
+    pub capacity: usize,
+    entries: HashMap<LayoutKey, CachedLayout>,
+    /// Incremented on every lookup, orders the entries by last use.
+    tick: u64,
+    hits: u64,
+    misses: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct LayoutKey {
+    /// Value, font and font size bits of each section.
+    sections: Vec<(String, AssetId<Font>, u32)>,
+    justify: JustifyText,
+    linebreak_behavior: BreakLineOn,
+    bounds: [u32; 2],
+    scale_factor: u32,
+}
+
+impl LayoutKey {
+    fn new(
+        sections: &[TextSection],
+        scale_factor: f32,
+        justify: JustifyText,
+        linebreak_behavior: BreakLineOn,
+        bounds: Vec2,
+    ) -> Self {
+        Self {
+            sections: sections
+                .iter()
+                .map(|section| {
+                    (
+                        section.value.clone(),
+                        section.style.font.id(),
+                        section.style.font_size.to_bits(),
+                    )
+                })
+                .collect(),
+            justify,
+            linebreak_behavior,
+            bounds: [bounds.x.to_bits(), bounds.y.to_bits()],
+            scale_factor: scale_factor.to_bits(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CachedLayout {
+    layout: TextLayoutInfo,
+    /// Keeps the fonts, and so their atlas sets, from being dropped.
+    fonts: Vec<Handle<Font>>,
+    last_used: u64,
+}
+
+impl Default for UiTextLayoutCache {
+    fn default() -> Self {
+        Self::new(256)
+    }
+}
+
+impl UiTextLayoutCache {
+    /// An empty cache keeping up to `capacity` layouts.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::default(),
+            tick: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Number of cached layouts.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Number of layouts reused since the cache was created.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Number of layouts that weren't cached since the cache was created.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// The cached layout of the sections, or lays them out with `queue_text` and caches the
+    /// result if it succeeds.
+    pub(crate) fn get_or_queue<E>(
+        &mut self,
+        sections: &[TextSection],
+        scale_factor: f32,
+        justify: JustifyText,
+        linebreak_behavior: BreakLineOn,
+        bounds: Vec2,
+        queue_text: impl FnOnce() -> Result<TextLayoutInfo, E>,
+    ) -> Result<TextLayoutInfo, E> {
+        self.tick += 1;
+        let key = LayoutKey::new(sections, scale_factor, justify, linebreak_behavior, bounds);
+        if let Some(cached) = self.entries.get_mut(&key) {
+            cached.last_used = self.tick;
+            self.hits += 1;
+            return Ok(cached.layout.clone());
+        }
+        self.misses += 1;
+        let layout = queue_text()?;
+        if self.capacity == 0 {
+            return Ok(layout);
+        }
+        while self.capacity <= self.entries.len() {
+            let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, cached)| cached.last_used)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+        self.entries.insert(
+            key,
+            CachedLayout {
+                layout: layout.clone(),
+                fonts: sections
+                    .iter()
+                    .map(|section| section.style.font.clone())
+                    .collect(),
+                last_used: self.tick,
+            },
+        );
+        Ok(layout)
+    }
+
+    fn remove_font(&mut self, font: AssetId<Font>) {
+        self.entries
+            .retain(|_, cached| cached.fonts.iter().all(|handle| handle.id() != font));
+    }
+}
+
+pub fn invalidate_ui_text_layout_cache(
+    mut cache: ResMut<UiTextLayoutCache>,
+    mut font_events: EventReader<AssetEvent<Font>>,
+    mut scale_factor_changed: EventReader<WindowScaleFactorChanged>,
+) {
+    if scale_factor_changed.read().last().is_some() {
+        cache.clear();
+    }
+    for event in font_events.read() {
+        if let AssetEvent::Modified { id } | AssetEvent::Removed { id } = event {
+            cache.remove_font(*id);
+        }
+    }
+}
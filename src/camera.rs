@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+/// What happens when a visible [`UiText`](crate::UiText) has no [`TargetCamera`] and there's no
+/// default UI camera to draw it to, so it can't be drawn.
+///
+/// Texts without this component log a warning, once per text until it finds a camera again.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RequireCamera {
+    /// Skips the text silently, for texts that are meant to be drawn only while a camera exists.
+    Ignore,
+    /// Logs a warning.
+    #[default]
+    Warn,
+    /// Panics, to catch missing cameras in tests and debug builds.
+    Panic,
+}
+
+impl RequireCamera {
+    #[cfg(feature = "render")]
+    pub(crate) fn report_missing(self, entity: Entity) {
+        let message = format!(
+            "{entity:?} has a UiText but no TargetCamera and there's no default UI camera, it won't be drawn."
+        );
+        match self {
+            RequireCamera::Ignore => {}
+            RequireCamera::Warn => warn!("{message}"),
+            RequireCamera::Panic => panic!("{message}"),
+        }
+    }
+}
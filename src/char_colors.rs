@@ -0,0 +1,75 @@
+use std::ops::Range;
+
+use bevy::prelude::*;
+
+use crate::UiText;
+
+/// Colors of ranges of a [`UiText`]'s characters, e.g. for syntax highlighting or flashing single
+/// letters, without splitting the text into sections.
+///
+/// The ranges are byte offsets into the text of all the sections joined together. A glyph inside
+/// a range is drawn in its color instead of its section's color, later ranges win where ranges
+/// overlap. The colors are applied when the glyphs are extracted, so changing them never lays
+/// the text out again. The colors of a [`UiTextColorAnim`](crate::UiTextColorAnim),
+/// [`ColorCycle`](crate::ColorCycle) or [`UiTextGradient`](crate::UiTextGradient) on the same
+/// text are multiplied with them, and [`TextTint`](crate::TextTint) and opacity are multiplied
+/// into them like into section colors.
+///
+/// Empty ranges and ranges past the end of the text are ignored, with a warning when the ranges
+/// or the text change.
+#[derive(Component, Clone, Debug, Default, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextCharColors(pub Vec<(Range<usize>, Color)>);
+
+impl UiTextCharColors {
+    /// The color of the character at `byte_offset` in a text `len` bytes long.
+    pub fn color_at(&self, byte_offset: usize, len: usize) -> Option<Color> {
+        self.0
+            .iter()
+            .rev()
+            .find(|(range, _)| is_valid_range(range, len) && range.contains(&byte_offset))
+            .map(|(_, color)| *color)
+    }
+}
+
+fn is_valid_range(range: &Range<usize>, len: usize) -> bool {
+    range.start < range.end && range.end <= len
+}
+
+/// Byte offset of the start of each section in the joined text of `sections`, followed by the
+/// length of the joined text.
+#[cfg(feature = "render")]
+pub(crate) fn section_offsets(sections: &[TextSection]) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(sections.len() + 1);
+    let mut offset = 0;
+    offsets.push(offset);
+    for section in sections {
+        offset += section.value.len();
+        offsets.push(offset);
+    }
+    offsets
+}
+
+#[allow(clippy::type_complexity)]
+pub fn warn_invalid_ui_text_char_colors(
+    text_query: Query<
+        (Entity, &UiText, &UiTextCharColors),
+        Or<(Changed<UiText>, Changed<UiTextCharColors>)>,
+    >,
+) {
+    for (entity, text, char_colors) in &text_query {
+        let len: usize = text
+            .sections
+            .iter()
+            .map(|section| section.value.len())
+            .sum();
+        for (range, _) in char_colors.iter() {
+            if !is_valid_range(range, len) {
+                warn!(
+                    "Ignoring the color range {range:?} of {entity:?}, its text is {len} bytes long."
+                );
+            }
+        }
+    }
+}
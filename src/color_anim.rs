@@ -0,0 +1,146 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::UiTextTime;
+
+/// How a [`UiTextColorAnim`] eases between its colors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorAnimCurve {
+    /// At a constant rate.
+    #[default]
+    Linear,
+    /// Slowly at first, then quickly.
+    EaseIn,
+    /// Quickly at first, then slowly.
+    EaseOut,
+    /// Slowly at both ends.
+    EaseInOut,
+}
+
+impl ColorAnimCurve {
+    /// Progress between the colors after `t`, the fraction of the duration that has passed.
+    pub fn ease(self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match self {
+            ColorAnimCurve::Linear => t,
+            ColorAnimCurve::EaseIn => t * t,
+            ColorAnimCurve::EaseOut => 1. - (1. - t) * (1. - t),
+            ColorAnimCurve::EaseInOut => t * t * (3. - 2. * t),
+        }
+    }
+}
+
+/// What a [`UiTextColorAnim`] does once its duration has passed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorAnimLoop {
+    /// Stays at `to`.
+    #[default]
+    Once,
+    /// Starts again from `from`.
+    Repeat,
+    /// Goes back to `from` and then forth again.
+    PingPong,
+}
+
+/// Animates the color of a [`UiText`](crate::UiText)'s sections from one color to another, e.g.
+/// to flash a warning or pulse a highlighted option.
+///
+/// The animated color is advanced by [`update_ui_text_color_anims`] with the
+/// [`UiTextTimeSource`](crate::UiTextTimeSource) and replaces the colors of the `sections`, all
+/// of them when empty, when the glyphs are extracted. The [`UiText`](crate::UiText) itself isn't
+/// changed, so the text isn't relaid out every frame. The colors of
+/// [`UiTextCharColors`](crate::UiTextCharColors), a [`ColorCycle`](crate::ColorCycle) or a
+/// [`UiTextGradient`](crate::UiTextGradient) are multiplied with it, and
+/// [`TextTint`](crate::TextTint) and opacity are multiplied into the color.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextColorAnim {
+    pub from: Color,
+    pub to: Color,
+    pub duration: Duration,
+    pub curve: ColorAnimCurve,
+    pub looping: ColorAnimLoop,
+    /// Indices of the animated sections, every section when empty.
+    pub sections: Vec<usize>,
+    elapsed: Duration,
+}
+
+impl Default for UiTextColorAnim {
+    fn default() -> Self {
+        Self::new(Color::WHITE, Color::WHITE, Duration::from_secs(1))
+    }
+}
+
+impl UiTextColorAnim {
+    pub fn new(from: Color, to: Color, duration: Duration) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            curve: ColorAnimCurve::default(),
+            looping: ColorAnimLoop::default(),
+            sections: Vec::new(),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    pub fn with_curve(mut self, curve: ColorAnimCurve) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    pub fn with_looping(mut self, looping: ColorAnimLoop) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    pub fn with_sections(mut self, sections: impl IntoIterator<Item = usize>) -> Self {
+        self.sections = sections.into_iter().collect();
+        self
+    }
+
+    /// Whether a [`ColorAnimLoop::Once`] animation has reached `to`.
+    pub fn is_finished(&self) -> bool {
+        self.looping == ColorAnimLoop::Once && self.duration <= self.elapsed
+    }
+
+    /// Starts the animation again from `from`.
+    pub fn restart(&mut self) {
+        self.elapsed = Duration::ZERO;
+    }
+
+    /// Whether the section at `index` is animated.
+    pub fn animates(&self, index: usize) -> bool {
+        self.sections.is_empty() || self.sections.contains(&index)
+    }
+
+    /// The current color, interpolated in linear color space.
+    pub fn color(&self) -> Color {
+        let duration = self.duration.as_secs_f32();
+        if duration <= 0. {
+            return self.to;
+        }
+        let cycles = self.elapsed.as_secs_f32() / duration;
+        let t = match self.looping {
+            ColorAnimLoop::Once => cycles.min(1.),
+            ColorAnimLoop::Repeat => cycles.fract(),
+            // 0 to 1 on even cycles and back to 0 on odd ones
+            ColorAnimLoop::PingPong => 1. - (cycles.rem_euclid(2.) - 1.).abs(),
+        };
+        LinearRgba::from(self.from)
+            .mix(&LinearRgba::from(self.to), self.curve.ease(t))
+            .into()
+    }
+}
+
+pub fn update_ui_text_color_anims(time: UiTextTime, mut anim_query: Query<&mut UiTextColorAnim>) {
+    for mut anim in &mut anim_query {
+        if !anim.is_finished() {
+            anim.elapsed += time.delta();
+        }
+    }
+}
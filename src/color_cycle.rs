@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+
+/// Cycles the color of each glyph of a [`UiText`](crate::UiText) through a palette over time,
+/// like classic demo scene text.
+///
+/// The colors replace the sections' colors when the glyphs are extracted, so cycling doesn't
+/// relayout the text. The colors of [`UiTextCharColors`](crate::UiTextCharColors), a
+/// [`UiTextColorAnim`](crate::UiTextColorAnim) or a [`UiTextGradient`](crate::UiTextGradient)
+/// are multiplied with the palette's, and [`TextTint`](crate::TextTint) and opacity are
+/// multiplied into the colors.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColorCycle {
+    pub palette: Vec<Color>,
+    /// Palette colors stepped through per second.
+    pub speed: f32,
+    /// Palette colors each glyph is ahead of the glyph before it.
+    pub per_glyph_phase: f32,
+}
+
+impl Default for ColorCycle {
+    fn default() -> Self {
+        Self {
+            palette: vec![
+                Color::srgb(1., 0.2, 0.2),
+                Color::srgb(1., 0.6, 0.1),
+                Color::srgb(1., 1., 0.2),
+                Color::srgb(0.2, 1., 0.3),
+                Color::srgb(0.2, 0.6, 1.),
+                Color::srgb(0.7, 0.3, 1.),
+            ],
+            speed: 8.,
+            per_glyph_phase: 1.,
+        }
+    }
+}
+
+impl ColorCycle {
+    /// Color of the glyph at `index` at `time` seconds, `None` for an empty palette.
+    pub fn color(&self, index: usize, time: f32) -> Option<Color> {
+        if self.palette.is_empty() {
+            return None;
+        }
+        let step = (self.speed * time - self.per_glyph_phase * index as f32).floor();
+        let len = self.palette.len() as f32;
+        Some(self.palette[step.rem_euclid(len) as usize % self.palette.len()])
+    }
+}
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+/// Skips extracting texts whose drawn rect is entirely outside their camera's viewport, e.g. for
+/// thousands of world anchored labels of which only a few are on screen.
+///
+/// Opt in by inserting the resource. Bevy's visibility checks don't know where a text is drawn
+/// on screen, so without it every visible text's glyphs are converted into UI nodes only to be
+/// clipped by the GPU. The rect is that of the text's laid out box after its transform, effects
+/// drawn outside it like waves, backgrounds with padding or bold offsets are kept by `margin`,
+/// in logical pixels. Texts drawn with a [`UiTextMaterial`](crate::UiTextMaterial) aren't culled.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq)]
+pub struct UiTextViewportCulling {
+    pub margin: f32,
+}
+
+impl UiTextViewportCulling {
+    pub fn with_margin(margin: f32) -> Self {
+        Self { margin }
+    }
+
+    /// Whether a text drawn to `rect` in UI coordinates is outside a viewport of `viewport_size`
+    /// logical pixels.
+    pub fn is_culled(&self, rect: Rect, viewport_size: Vec2) -> bool {
+        Rect::from_corners(Vec2::ZERO, viewport_size)
+            .inflate(self.margin)
+            .intersect(rect)
+            .is_empty()
+    }
+}
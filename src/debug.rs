@@ -0,0 +1,308 @@
+use bevy::math::Affine3A;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use bevy::text::{Text2dBounds, TextLayoutInfo};
+
+use crate::lines::{glyph_lines, line_baseline};
+use crate::{
+    anchor_point, drawn_text_transform, is_valid_scale_factor, resolve_bounds, scroll_clip_width,
+    HorizontalScroll, MinPixelSize, ScreenOffset, TextSnapping, UiText, UiTextBounds, UiTextGlyphs,
+    UiTextKeepUpright, UiTextLayoutScale, UiTextScaling,
+};
+
+/// Draws the bounds, layout, anchor and baselines of every visible [`UiText`] with [`Gizmos`].
+///
+/// Requires the `debug` feature.
+pub struct IndependentTextDebugPlugin;
+
+impl Plugin for IndependentTextDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiTextDebugSettings>().add_systems(
+            PostUpdate,
+            draw_ui_text_debug
+                .run_if(|settings: Res<UiTextDebugSettings>| settings.enabled)
+                .after(crate::update_ui_independent_text_layout)
+                .after(crate::position_ui_node_texts),
+        );
+    }
+}
+
+/// Configures what [`IndependentTextDebugPlugin`] draws. Set a color to `None` to skip drawing it.
+#[derive(Resource, Clone, Debug)]
+pub struct UiTextDebugSettings {
+    /// Nothing is drawn until this is set.
+    pub enabled: bool,
+    pub bounds: Option<Color>,
+    pub layout: Option<Color>,
+    pub anchor: Option<Color>,
+    pub baselines: Option<Color>,
+    pub glyphs: Option<Color>,
+}
+
+impl Default for UiTextDebugSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bounds: Some(Color::srgb(1., 0.5, 0.)),
+            layout: Some(Color::srgb(0., 1., 0.)),
+            anchor: Some(Color::srgb(1., 0., 1.)),
+            baselines: Some(Color::srgb(0., 0.5, 1.)),
+            glyphs: None,
+        }
+    }
+}
+
+/// Draws the overlay with the transform the text's glyphs are extracted with.
+#[allow(clippy::type_complexity)]
+pub fn draw_ui_text_debug(
+    mut gizmos: Gizmos,
+    settings: Res<UiTextDebugSettings>,
+    default_ui_camera: DefaultUiCamera,
+    camera_query: Query<(&Camera, &GlobalTransform, Option<&OrthographicProjection>)>,
+    text_query: Query<(
+        Ref<GlobalTransform>,
+        &UiText,
+        &ViewVisibility,
+        &TextLayoutInfo,
+        (Option<&UiTextBounds>, Option<&Text2dBounds>),
+        Option<&TargetCamera>,
+        Option<&UiTextScaling>,
+        Option<&ScreenOffset>,
+        Option<&UiTextLayoutScale>,
+        Option<&Anchor>,
+        Option<(&UiTextKeepUpright, &Transform)>,
+        Option<&TextSnapping>,
+        Option<&MinPixelSize>,
+        Option<&HorizontalScroll>,
+    )>,
+) {
+    for (
+        global_transform,
+        text,
+        view_visibility,
+        text_layout,
+        (maybe_bounds, maybe_text_2d_bounds),
+        maybe_camera,
+        maybe_scaling,
+        maybe_screen_offset,
+        maybe_layout_scale,
+        maybe_anchor,
+        maybe_keep_upright,
+        maybe_snapping,
+        maybe_min_size,
+        maybe_scroll,
+    ) in &text_query
+    {
+        if !view_visibility.get() {
+            continue;
+        }
+        let Some((camera_entity, (camera, camera_transform, projection))) = maybe_camera
+            .map(TargetCamera::entity)
+            .or(default_ui_camera.get())
+            .and_then(|entity| Some((entity, camera_query.get(entity).ok()?)))
+        else {
+            continue;
+        };
+        let scale_factor = camera.target_scaling_factor().unwrap_or(1.);
+        if !is_valid_scale_factor(scale_factor) {
+            continue;
+        }
+        let snap = maybe_snapping
+            .copied()
+            .unwrap_or_default()
+            .snaps(global_transform.is_changed());
+        let drawn_transform = drawn_text_transform(
+            &global_transform,
+            maybe_keep_upright,
+            maybe_min_size,
+            text,
+            projection,
+        );
+        let glyphs = UiTextGlyphs {
+            scaling: maybe_scaling.copied().unwrap_or_default(),
+            anchor: maybe_anchor,
+            snap,
+            screen_offset: maybe_screen_offset.map_or(Vec2::ZERO, |offset| offset.0),
+            scroll: maybe_scroll.map_or(0., |scroll| scroll.0),
+            clip_width: scroll_clip_width(maybe_scroll, maybe_bounds),
+            layout_scale_factor: maybe_layout_scale.map_or(scale_factor, UiTextLayoutScale::get),
+            ..UiTextGlyphs::new(
+                text,
+                text_layout,
+                &drawn_transform,
+                camera_entity,
+                scale_factor,
+            )
+        };
+        // the drawn box, and the glyphs moved by the text's scroll within it
+        let (box_transform, inverse_scale_factor) = glyphs.transform();
+        let (glyph_transform, _) = glyphs.glyph_transform();
+        let size = text_layout.logical_size * inverse_scale_factor;
+        let visible_size = glyphs.visible_size();
+
+        // local text space -> UI coordinates -> world space of the camera
+        let to_world = |transform: Affine3A, point: Vec2| {
+            let point = transform.transform_point3(point.extend(0.)).truncate();
+            camera
+                .viewport_to_world_2d(camera_transform, point)
+                .unwrap_or(point)
+        };
+        let mut draw_lines = |transform: Affine3A, points: &[Vec2], color: Color| {
+            gizmos.linestrip_2d(
+                points.iter().map(|point| to_world(transform, *point)),
+                color,
+            );
+        };
+
+        if let Some(color) = settings.layout {
+            draw_lines(
+                glyph_transform,
+                &outline(Rect::from_corners(Vec2::ZERO, size)),
+                color,
+            );
+        }
+        let bounds = resolve_bounds(maybe_bounds, maybe_text_2d_bounds);
+        if let (Some(color), Some(bounds)) = (settings.bounds, bounds) {
+            for [start, end] in bounds_edges(bounds, visible_size, maybe_anchor) {
+                draw_lines(box_transform, &[start, end], color);
+            }
+        }
+        if let Some(color) = settings.glyphs {
+            for glyph in &text_layout.glyphs {
+                draw_lines(
+                    glyph_transform,
+                    &outline(Rect::from_center_size(
+                        glyph.position * inverse_scale_factor,
+                        glyph.size * inverse_scale_factor,
+                    )),
+                    color,
+                );
+            }
+        }
+        if let Some(color) = settings.baselines {
+            for line in glyph_lines(&text_layout.glyphs) {
+                let glyphs = &text_layout.glyphs[line];
+                let (first, last) = (&glyphs[0], &glyphs[glyphs.len() - 1]);
+                let y = line_baseline(glyphs) * inverse_scale_factor;
+                let left = (first.position.x - 0.5 * first.size.x) * inverse_scale_factor;
+                let right = (last.position.x + 0.5 * last.size.x) * inverse_scale_factor;
+                gizmos.line_2d(
+                    to_world(glyph_transform, Vec2::new(left, y)),
+                    to_world(glyph_transform, Vec2::new(right, y)),
+                    color,
+                );
+            }
+        }
+        if let Some(color) = settings.anchor {
+            gizmos.circle_2d(
+                to_world(box_transform, anchor_point(maybe_anchor, visible_size)),
+                3.,
+                color,
+            );
+        }
+    }
+}
+
+/// The edges of the `bounds` of a text drawn in a box of `visible_size`.
+///
+/// The bounds are anchored like the box, so justified lines line up with them. An unbounded axis
+/// spans the box and only the edges of the bounded axis are drawn, e.g. the wrap width of width
+/// only bounds.
+fn bounds_edges(bounds: Vec2, visible_size: Vec2, anchor: Option<&Anchor>) -> Vec<[Vec2; 2]> {
+    let bounded = BVec2::new(bounds.x.is_finite(), bounds.y.is_finite());
+    let bounds = Vec2::select(bounded, bounds, visible_size);
+    let min = anchor_point(anchor, visible_size) - anchor_point(anchor, bounds);
+    let [top_left, top_right, bottom_right, bottom_left, _] =
+        outline(Rect::from_corners(min, min + bounds));
+    let mut edges = Vec::with_capacity(4);
+    if bounded.x {
+        edges.extend([[top_left, bottom_left], [top_right, bottom_right]]);
+    }
+    if bounded.y {
+        edges.extend([[top_left, top_right], [bottom_left, bottom_right]]);
+    }
+    edges
+}
+
+/// The corners of `rect` in order, back to the first, as a closed line strip.
+fn outline(rect: Rect) -> [Vec2; 5] {
+    [
+        rect.min,
+        Vec2::new(rect.max.x, rect.min.y),
+        rect.max,
+        Vec2::new(rect.min.x, rect.max.y),
+        rect.min,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounded_axes_draw_their_edges() {
+        let visible_size = Vec2::new(40., 20.);
+        assert_eq!(
+            bounds_edges(Vec2::new(60., 30.), visible_size, Some(&Anchor::TopLeft)).len(),
+            4
+        );
+        // the wrap width spans the height of the drawn box
+        assert_eq!(
+            bounds_edges(
+                Vec2::new(60., f32::INFINITY),
+                visible_size,
+                Some(&Anchor::TopLeft)
+            ),
+            [
+                [Vec2::ZERO, Vec2::new(0., 20.)],
+                [Vec2::new(60., 0.), Vec2::new(60., 20.)],
+            ]
+        );
+        assert_eq!(
+            bounds_edges(
+                Vec2::new(f32::INFINITY, 30.),
+                visible_size,
+                Some(&Anchor::TopLeft)
+            ),
+            [
+                [Vec2::ZERO, Vec2::new(40., 0.)],
+                [Vec2::new(0., 30.), Vec2::new(40., 30.)],
+            ]
+        );
+    }
+
+    #[test]
+    fn bounds_are_anchored_like_the_drawn_box() {
+        // centered bounds reach past both sides of a centered box
+        assert_eq!(
+            bounds_edges(Vec2::new(60., f32::INFINITY), Vec2::new(40., 20.), None),
+            [
+                [Vec2::new(-10., 0.), Vec2::new(-10., 20.)],
+                [Vec2::new(50., 0.), Vec2::new(50., 20.)],
+            ]
+        );
+    }
+
+    #[test]
+    fn text_2d_bounds_are_drawn_while_ui_text_bounds_are_unbounded() {
+        let text_2d_bounds = Text2dBounds {
+            size: Vec2::new(60., f32::MAX),
+        };
+        // the bundle's unbounded `UiTextBounds` doesn't hide the 2d bounds
+        assert_eq!(
+            resolve_bounds(Some(&UiTextBounds::UNBOUNDED), Some(&text_2d_bounds)),
+            Some(Vec2::new(60., f32::INFINITY))
+        );
+        assert_eq!(
+            resolve_bounds(Some(&UiTextBounds::new(30., 10.)), Some(&text_2d_bounds)),
+            Some(Vec2::new(30., 10.))
+        );
+        assert_eq!(
+            resolve_bounds(
+                Some(&UiTextBounds::UNBOUNDED),
+                Some(&Text2dBounds::UNBOUNDED)
+            ),
+            None
+        );
+    }
+}
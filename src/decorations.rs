@@ -0,0 +1,123 @@
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy::text::PositionedGlyph;
+
+#[cfg(feature = "render")]
+use crate::lines::{glyph_lines, line_baseline};
+
+/// Lines drawn along a section of a [`UiText`](crate::UiText).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextDecoration {
+    /// A line just below the baseline, e.g. for hyperlinks.
+    pub underline: bool,
+    /// A line through the middle of the lowercase letters, e.g. for completed objectives.
+    pub strikethrough: bool,
+}
+
+impl TextDecoration {
+    pub const UNDERLINE: Self = Self {
+        underline: true,
+        strikethrough: false,
+    };
+    pub const STRIKETHROUGH: Self = Self {
+        underline: false,
+        strikethrough: true,
+    };
+}
+
+/// Per section underlines and strikethroughs, drawn in the sections' colors when the glyphs are
+/// extracted.
+///
+/// A decoration spans each run of a section's glyphs on a line, from the left edge of its first
+/// glyph to the right edge of its last, including the spaces between them. Fonts' own
+/// decoration metrics aren't available, so the lines are placed relative to the line's baseline
+/// by fractions of the section's font size.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextDecorations {
+    /// Decorations of each section by index. Sections without an entry aren't decorated.
+    pub sections: Vec<TextDecoration>,
+    /// Thickness of the lines in logical pixels.
+    pub thickness: f32,
+}
+
+impl Default for UiTextDecorations {
+    fn default() -> Self {
+        Self {
+            sections: Vec::new(),
+            thickness: 1.,
+        }
+    }
+}
+
+impl UiTextDecorations {
+    pub fn new(sections: impl IntoIterator<Item = TextDecoration>) -> Self {
+        Self {
+            sections: sections.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    pub fn section(&self, index: usize) -> TextDecoration {
+        self.sections.get(index).copied().unwrap_or_default()
+    }
+
+    /// The rects of the lines and the index of the section they decorate, in the layout's
+    /// pixels with the origin at its top left.
+    ///
+    /// `layout_scale_factor` is the scale factor the glyphs were laid out at.
+    #[cfg(feature = "render")]
+    pub(crate) fn rects(
+        &self,
+        sections: &[TextSection],
+        glyphs: &[PositionedGlyph],
+        layout_scale_factor: f32,
+    ) -> Vec<(usize, Rect)> {
+        let thickness = (self.thickness * layout_scale_factor).max(1.);
+        let mut rects = Vec::new();
+        for line in glyph_lines(glyphs) {
+            let line = &glyphs[line];
+            let baseline = line_baseline(line);
+            for run in line.chunk_by(|a, b| a.section_index == b.section_index) {
+                let section_index = run[0].section_index;
+                let decoration = self.section(section_index);
+                if !decoration.underline && !decoration.strikethrough {
+                    continue;
+                }
+                let font_size = sections
+                    .get(section_index)
+                    .map_or(0., |section| section.style.font_size * layout_scale_factor);
+                let left = run
+                    .iter()
+                    .map(|glyph| glyph.position.x - 0.5 * glyph.size.x)
+                    .fold(f32::INFINITY, f32::min);
+                let right = run
+                    .iter()
+                    .map(|glyph| glyph.position.x + 0.5 * glyph.size.x)
+                    .fold(f32::NEG_INFINITY, f32::max);
+                let line_rect = |center_y: f32| {
+                    Rect::new(
+                        left,
+                        center_y - 0.5 * thickness,
+                        right,
+                        center_y + 0.5 * thickness,
+                    )
+                };
+                if decoration.underline {
+                    rects.push((section_index, line_rect(baseline + 0.1 * font_size)));
+                }
+                if decoration.strikethrough {
+                    rects.push((section_index, line_rect(baseline - 0.3 * font_size)));
+                }
+            }
+        }
+        rects
+    }
+}
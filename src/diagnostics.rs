@@ -0,0 +1,109 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy::prelude::*;
+use bevy::render::view::VisibilitySystems;
+#[cfg(feature = "render")]
+use bevy::render::RenderApp;
+
+use crate::{JustRelaidOut, UiText};
+
+/// Records [`Diagnostic`]s of the crate's texts, printed by
+/// [`LogDiagnosticsPlugin`](bevy::diagnostic::LogDiagnosticsPlugin) along with the frame time, to
+/// tell whether they cause a spike:
+///
+/// * [`VISIBLE_TEXTS`](Self::VISIBLE_TEXTS), the number of visible [`UiText`]s.
+/// * [`EXTRACTED_GLYPHS`](Self::EXTRACTED_GLYPHS), the number of [`ExtractedUiNode`]s
+///   produced by [`extract_text_sprite`](crate::extract_text_sprite), glyphs and everything drawn
+///   with them. Extraction runs after the main world's update, so this is the previous frame's.
+/// * [`LAYOUT_TIME`](Self::LAYOUT_TIME), the time spent in
+///   [`update_ui_independent_text_layout`](crate::update_ui_independent_text_layout).
+/// * [`RELAYOUTS`](Self::RELAYOUTS), the number of texts laid out in the frame.
+///
+/// Without the plugin nothing is measured.
+///
+/// [`ExtractedUiNode`]: bevy::ui::ExtractedUiNode
+pub struct IndependentTextDiagnosticsPlugin;
+
+impl IndependentTextDiagnosticsPlugin {
+    pub const VISIBLE_TEXTS: DiagnosticPath =
+        DiagnosticPath::const_new("ui_independent_text/visible_texts");
+    pub const EXTRACTED_GLYPHS: DiagnosticPath =
+        DiagnosticPath::const_new("ui_independent_text/extracted_glyphs");
+    pub const LAYOUT_TIME: DiagnosticPath =
+        DiagnosticPath::const_new("ui_independent_text/layout_time_ms");
+    pub const RELAYOUTS: DiagnosticPath =
+        DiagnosticPath::const_new("ui_independent_text/relayouts");
+}
+
+impl Plugin for IndependentTextDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        let extract_stats = UiTextExtractStats::default();
+        #[cfg(feature = "render")]
+        if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app.insert_resource(extract_stats.clone());
+        }
+        app.register_diagnostic(Diagnostic::new(Self::VISIBLE_TEXTS).with_smoothing_factor(0.))
+            .register_diagnostic(Diagnostic::new(Self::EXTRACTED_GLYPHS).with_smoothing_factor(0.))
+            .register_diagnostic(Diagnostic::new(Self::LAYOUT_TIME).with_suffix("ms"))
+            .register_diagnostic(Diagnostic::new(Self::RELAYOUTS).with_smoothing_factor(0.))
+            .insert_resource(extract_stats)
+            .init_resource::<UiTextLayoutStats>()
+            .add_systems(
+                PostUpdate,
+                measure_ui_text_diagnostics
+                    .after(crate::update_ui_independent_text_layout)
+                    .after(VisibilitySystems::CheckVisibility),
+            );
+    }
+}
+
+/// Time spent laying out texts in the current frame, written by
+/// [`update_ui_independent_text_layout`](crate::update_ui_independent_text_layout) when present.
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub struct UiTextLayoutStats {
+    pub layout_time: Duration,
+}
+
+/// Number of nodes extracted for the texts, shared between the main and render worlds.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct UiTextExtractStats {
+    extracted_nodes: Arc<AtomicUsize>,
+}
+
+impl UiTextExtractStats {
+    pub fn extracted_nodes(&self) -> usize {
+        self.extracted_nodes.load(Ordering::Relaxed)
+    }
+
+    #[cfg(feature = "render")]
+    pub(crate) fn set_extracted_nodes(&self, count: usize) {
+        self.extracted_nodes.store(count, Ordering::Relaxed);
+    }
+}
+
+pub fn measure_ui_text_diagnostics(
+    mut diagnostics: Diagnostics,
+    layout_stats: Res<UiTextLayoutStats>,
+    extract_stats: Res<UiTextExtractStats>,
+    text_query: Query<&ViewVisibility, With<UiText>>,
+    relaid_out_query: Query<(), (With<UiText>, With<JustRelaidOut>)>,
+) {
+    diagnostics.add_measurement(&IndependentTextDiagnosticsPlugin::VISIBLE_TEXTS, || {
+        text_query
+            .iter()
+            .filter(|visibility| visibility.get())
+            .count() as f64
+    });
+    diagnostics.add_measurement(&IndependentTextDiagnosticsPlugin::EXTRACTED_GLYPHS, || {
+        extract_stats.extracted_nodes() as f64
+    });
+    diagnostics.add_measurement(&IndependentTextDiagnosticsPlugin::LAYOUT_TIME, || {
+        layout_stats.layout_time.as_secs_f64() * 1000.
+    });
+    diagnostics.add_measurement(&IndependentTextDiagnosticsPlugin::RELAYOUTS, || {
+        relaid_out_query.iter().count() as f64
+    });
+}
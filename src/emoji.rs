@@ -0,0 +1,89 @@
+use bevy::prelude::*;
+
+/// The glyphs of a [`UiText`](crate::UiText) that have color bitmaps in their font, like color
+/// emoji. They're drawn without their section's color, only the [`TextTint`](crate::TextTint)
+/// is applied.
+///
+/// Inserted by `detect_ui_text_color_glyphs` with the `color_emoji` feature.
+///
+/// Bevy's text pipeline only rasterizes glyph outlines into single color atlases. Glyphs that are
+/// only color bitmaps, as in most emoji fonts, aren't laid out at all, and glyphs with both
+/// an outline and a bitmap are drawn from their outline as a silhouette. Skipping the section
+/// color keeps those silhouettes from being recolored, but doesn't draw them in full color.
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+pub struct UiTextColorGlyphs(pub(crate) Vec<bool>);
+
+impl UiTextColorGlyphs {
+    /// Whether the glyph at `glyph_index` in the text's layout is a color glyph.
+    pub fn is_color(&self, glyph_index: usize) -> bool {
+        self.0.get(glyph_index).copied().unwrap_or(false)
+    }
+}
+
+#[cfg(feature = "color_emoji")]
+pub use detect::*;
+
+#[cfg(feature = "color_emoji")]
+mod detect {
+    use ab_glyph::Font as _;
+    use bevy::prelude::*;
+    use bevy::text::TextLayoutInfo;
+
+    use super::UiTextColorGlyphs;
+    use crate::{font_for_char, DefaultFontFallback, FontFallback, UiText};
+
+    /// Finds the color glyphs of texts with a new layout.
+    #[allow(clippy::type_complexity)]
+    pub fn detect_ui_text_color_glyphs(
+        mut commands: Commands,
+        fonts: Res<Assets<Font>>,
+        default_fallback: Res<DefaultFontFallback>,
+        mut text_query: Query<
+            (
+                Entity,
+                &UiText,
+                &TextLayoutInfo,
+                Option<&FontFallback>,
+                Option<&mut UiTextColorGlyphs>,
+            ),
+            Changed<TextLayoutInfo>,
+        >,
+    ) {
+        for (entity, text, layout, maybe_fallback, maybe_color_glyphs) in &mut text_query {
+            let fallbacks =
+                maybe_fallback.map_or(default_fallback.as_slice(), |fallback| fallback.as_slice());
+            let color_glyphs = UiTextColorGlyphs(
+                layout
+                    .glyphs
+                    .iter()
+                    .map(|glyph| {
+                        let Some(section) = text.sections.get(glyph.section_index) else {
+                            return false;
+                        };
+                        let Some(c) = section
+                            .value
+                            .get(glyph.byte_index..)
+                            .and_then(|value| value.chars().next())
+                        else {
+                            return false;
+                        };
+                        let handle = font_for_char(c, &section.style.font, fallbacks, &fonts);
+                        fonts.get(handle).is_some_and(|font| {
+                            let glyph_id = font.font.glyph_id(c);
+                            font.font.glyph_raster_image2(glyph_id, u16::MAX).is_some()
+                        })
+                    })
+                    .collect(),
+            );
+            match maybe_color_glyphs {
+                Some(mut current) => {
+                    current.set_if_neq(color_glyphs);
+                }
+                None if color_glyphs.0.contains(&true) => {
+                    commands.entity(entity).insert(color_glyphs);
+                }
+                None => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,880 @@
+use bevy::ecs::entity::EntityHashSet;
+use bevy::math::Affine3A;
+use bevy::prelude::*;
+use bevy::render::Extract;
+use bevy::sprite::Anchor;
+use bevy::text::{PositionedGlyph, TextLayoutInfo};
+use bevy::ui::{ExtractedUiNode, ExtractedUiNodes, NodeType};
+
+#[cfg(feature = "material")]
+use crate::UsesUiTextMaterial;
+use crate::{
+    drawn_global_transform, is_valid_scale_factor, normalize_bounds, section_offsets,
+    text_transform, ui_text_background_nodes, ui_text_baked_image_node,
+    ui_text_image_background_nodes, ui_text_stack_index, vertical_glyph_rotation, ColorCycle,
+    HorizontalScroll, InheritedUiTextOpacity, LabelLod, MinPixelSize, RelativeDepth, RequireCamera,
+    ScreenOffset, SyntheticBold, TextSelection, TextSnapping, TextTint, UiText, UiTextAlpha,
+    UiTextBackground, UiTextBakedImage, UiTextBlink, UiTextBounds, UiTextCaret, UiTextCharColors,
+    UiTextColorAnim, UiTextColorGlyphs, UiTextDecorations, UiTextExtractStats, UiTextGlow,
+    UiTextGradient, UiTextImageBackground, UiTextKeepUpright, UiTextLayoutScale, UiTextOrientation,
+    UiTextOutline, UiTextScaling, UiTextSectionSynthesis, UiTextShadow, UiTextStackIndexFn,
+    UiTextTime, UiTextViewportCulling, WaveText, GLOW_LAYERS,
+};
+
+/// A laid out text to be converted into [`ExtractedUiNode`]s by [`ui_text_glyph_nodes`].
+#[derive(Clone, Copy)]
+pub struct UiTextGlyphs<'a> {
+    pub text: &'a Text,
+    pub layout: &'a TextLayoutInfo,
+    pub global_transform: &'a GlobalTransform,
+    /// Stack index of the nodes, the z translation of the text's transform unless it has a
+    /// [`RelativeDepth`](crate::RelativeDepth).
+    pub stack_index: u32,
+    pub scaling: UiTextScaling,
+    pub synthesis: Option<&'a UiTextSectionSynthesis>,
+    pub synthetic_bold: Option<&'a SyntheticBold>,
+    pub tint: Option<&'a TextTint>,
+    pub char_colors: Option<&'a UiTextCharColors>,
+    pub color_anim: Option<&'a UiTextColorAnim>,
+    pub color_cycle: Option<&'a ColorCycle>,
+    pub gradient: Option<&'a UiTextGradient>,
+    pub color_glyphs: Option<&'a UiTextColorGlyphs>,
+    pub shadow: Option<&'a UiTextShadow>,
+    pub outline: Option<&'a UiTextOutline>,
+    pub glow: Option<&'a UiTextGlow>,
+    pub decorations: Option<&'a UiTextDecorations>,
+    /// Multiplied into the alpha of everything drawn for the text, from its
+    /// [`InheritedUiTextOpacity`].
+    pub opacity: f32,
+    pub selection: Option<&'a TextSelection>,
+    pub caret: Option<&'a UiTextCaret>,
+    pub orientation: UiTextOrientation,
+    /// The point of the text placed at its transform.
+    pub anchor: Option<&'a Anchor>,
+    /// Whether the text's position is snapped to the physical pixel grid, from its
+    /// [`TextSnapping`].
+    pub snap: bool,
+    /// Offset in logical pixels from a [`ScreenOffset`].
+    pub screen_offset: Vec2,
+    pub wave: Option<&'a WaveText>,
+    /// Offset the glyphs are scrolled left by, from a [`HorizontalScroll`].
+    pub scroll: f32,
+    /// Width of the window the scrolled glyphs are clipped to, the width of the text's
+    /// [`UiTextBounds`](crate::UiTextBounds). `None` for texts that don't scroll.
+    pub clip_width: Option<f32>,
+    /// Elapsed time in seconds, drives animated effects.
+    pub time: f32,
+    /// The camera the text is drawn to.
+    pub camera_entity: Entity,
+    /// Scale factor of the camera's render target.
+    pub scale_factor: f32,
+    /// Scale factor the text was laid out and its glyphs rasterized at, from its
+    /// [`UiTextLayoutScale`].
+    pub layout_scale_factor: f32,
+}
+
+impl<'a> UiTextGlyphs<'a> {
+    /// A text drawn without effects, with the defaults of every optional component.
+    pub fn new(
+        text: &'a Text,
+        layout: &'a TextLayoutInfo,
+        global_transform: &'a GlobalTransform,
+        camera_entity: Entity,
+        scale_factor: f32,
+    ) -> Self {
+        Self {
+            text,
+            layout,
+            global_transform,
+            stack_index: 0,
+            scaling: UiTextScaling::default(),
+            synthesis: None,
+            synthetic_bold: None,
+            tint: None,
+            char_colors: None,
+            color_anim: None,
+            color_cycle: None,
+            gradient: None,
+            color_glyphs: None,
+            shadow: None,
+            outline: None,
+            glow: None,
+            decorations: None,
+            opacity: 1.,
+            selection: None,
+            caret: None,
+            orientation: UiTextOrientation::default(),
+            anchor: None,
+            snap: false,
+            screen_offset: Vec2::ZERO,
+            wave: None,
+            scroll: 0.,
+            clip_width: None,
+            time: 0.,
+            camera_entity,
+            scale_factor,
+            layout_scale_factor: scale_factor,
+        }
+    }
+
+    /// The factor converting the layout's pixels into the text's local space.
+    fn inverse_scale_factor(&self) -> f32 {
+        match self.scaling {
+            // laid out with a scale factor of 1
+            UiTextScaling::Physical => 1.,
+            // glyph positions and atlases are in the pixels of the scale factor the text was
+            // laid out at, which can lag behind the camera's for a frame
+            UiTextScaling::Logical => self.layout_scale_factor.recip(),
+        }
+    }
+
+    /// How far the glyphs are scrolled left and the width they're clipped to, for texts wider
+    /// than their scroll window.
+    pub(crate) fn scroll_window(&self) -> Option<(f32, f32)> {
+        let width = self.layout.logical_size.x * self.inverse_scale_factor();
+        let clip_width = self.clip_width?;
+        (clip_width < width).then(|| (self.scroll.clamp(0., width - clip_width), clip_width))
+    }
+
+    /// Size of the drawn part of the text in its local space.
+    pub(crate) fn visible_size(&self) -> Vec2 {
+        let mut size = self.layout.logical_size * self.inverse_scale_factor();
+        if let Some((_, clip_width)) = self.scroll_window() {
+            size.x = clip_width;
+        }
+        size
+    }
+
+    /// The transform from the text's local space, with the origin at the top left of the
+    /// drawn text, and the factor converting the layout's pixels into that space.
+    pub(crate) fn transform(&self) -> (Affine3A, f32) {
+        let transform = match self.scaling {
+            // the whole text is scaled to logical pixels
+            UiTextScaling::Physical => {
+                Affine3A::from_scale(Vec3::splat(self.scale_factor.recip()))
+                    * text_transform(
+                        self.global_transform,
+                        self.visible_size(),
+                        self.anchor,
+                        1.,
+                        self.screen_offset * self.scale_factor,
+                        self.snap,
+                    )
+            }
+            UiTextScaling::Logical => text_transform(
+                self.global_transform,
+                self.visible_size(),
+                self.anchor,
+                self.scale_factor,
+                self.screen_offset,
+                self.snap,
+            ),
+        };
+        (transform, self.inverse_scale_factor())
+    }
+
+    /// Like [`transform`](Self::transform), with the glyphs moved by the text's scroll, the
+    /// transform the glyphs are drawn with.
+    pub(crate) fn glyph_transform(&self) -> (Affine3A, f32) {
+        let (mut transform, inverse_scale_factor) = self.transform();
+        if let Some((scroll, _)) = self.scroll_window() {
+            transform *= Affine3A::from_translation(Vec3::new(-scroll, 0., 0.));
+        }
+        (transform, inverse_scale_factor)
+    }
+
+    /// The clip rect in UI coordinates of texts scrolled by a [`HorizontalScroll`].
+    pub(crate) fn clip(&self) -> Option<Rect> {
+        self.scroll_window()?;
+        Some(self.screen_rect())
+    }
+
+    /// The bounding rect in UI coordinates of the drawn part of the text.
+    pub(crate) fn screen_rect(&self) -> Rect {
+        let (transform, _) = self.transform();
+        let size = self.visible_size();
+        let corners = [
+            Vec2::ZERO,
+            Vec2::new(size.x, 0.),
+            size,
+            Vec2::new(0., size.y),
+        ]
+        .map(|corner| transform.transform_point3(corner.extend(0.)).truncate());
+        let (min, max) = corners.into_iter().fold(
+            (Vec2::INFINITY, Vec2::NEG_INFINITY),
+            |(min, max), corner| (min.min(corner), max.max(corner)),
+        );
+        Rect { min, max }
+    }
+}
+
+/// The global transform a text is drawn with, after its [`UiTextKeepUpright`] and
+/// [`MinPixelSize`].
+pub(crate) fn drawn_text_transform(
+    global_transform: &GlobalTransform,
+    keep_upright: Option<(&UiTextKeepUpright, &Transform)>,
+    min_size: Option<&MinPixelSize>,
+    text: &Text,
+    projection: Option<&OrthographicProjection>,
+) -> GlobalTransform {
+    let global_transform = drawn_global_transform(global_transform, keep_upright);
+    let min_size_scale = min_size.map_or(1., |min_size| {
+        min_size.scale(text, &global_transform, projection)
+    });
+    if min_size_scale == 1. {
+        global_transform
+    } else {
+        GlobalTransform::from(
+            global_transform.affine() * Affine3A::from_scale(Vec3::splat(min_size_scale)),
+        )
+    }
+}
+
+/// Width of the window a text with a [`HorizontalScroll`] is clipped to, the width of its
+/// [`UiTextBounds`](crate::UiTextBounds).
+pub(crate) fn scroll_clip_width(
+    scroll: Option<&HorizontalScroll>,
+    bounds: Option<&UiTextBounds>,
+) -> Option<f32> {
+    scroll
+        .and(bounds)
+        .map(|bounds| normalize_bounds(bounds.size).x)
+        .filter(|width| width.is_finite())
+}
+
+/// Converts the glyphs of a laid out text into [`ExtractedUiNode`]s, one per glyph,
+/// without inserting them into [`ExtractedUiNodes`].
+///
+/// Used by [`extract_text_sprite`], and can be called directly to collect the nodes into
+/// a custom buffer for non-default UI render pipelines.
+pub fn ui_text_glyph_nodes(
+    glyphs: UiTextGlyphs,
+    texture_atlases: &Assets<TextureAtlasLayout>,
+    nodes: &mut impl Extend<ExtractedUiNode>,
+) {
+    let UiTextGlyphs {
+        text,
+        layout,
+        stack_index,
+        synthesis,
+        synthetic_bold,
+        tint,
+        char_colors,
+        color_anim,
+        color_cycle,
+        gradient,
+        color_glyphs,
+        shadow,
+        outline,
+        glow,
+        decorations,
+        opacity,
+        selection,
+        caret,
+        orientation,
+        wave,
+        time,
+        camera_entity,
+        ..
+    } = glyphs;
+    let (transform, inverse_scale_factor) = glyphs.glyph_transform();
+    let clip = glyphs.clip();
+
+    let (italic_shear, bold_offset) = synthesis.map_or((Mat4::IDENTITY, 0.), |synthesis| {
+        (
+            synthesis.italic_shear(),
+            synthesis.bold_offset * inverse_scale_factor,
+        )
+    });
+
+    // `inverse_scale_factor` is also the size of a physical pixel in the text's local space
+    let bold_offsets: Vec<f32> = synthetic_bold
+        .map(|bold| bold.offsets(inverse_scale_factor).collect())
+        .unwrap_or_default();
+
+    if let Some(selection) = selection {
+        // emitted before the glyphs so they're drawn behind them
+        let mut color = LinearRgba::from(selection.color);
+        color.alpha *= opacity;
+        nodes.extend(
+            selection
+                .line_rects(&layout.glyphs)
+                .into_iter()
+                .map(|line_rect| ExtractedUiNode {
+                    stack_index,
+                    transform: transform
+                        * Mat4::from_translation(
+                            line_rect.center().extend(0.) * inverse_scale_factor,
+                        ),
+                    color,
+                    rect: Rect::from_corners(Vec2::ZERO, line_rect.size() * inverse_scale_factor),
+                    image: AssetId::default(),
+                    atlas_size: None,
+                    clip,
+                    flip_x: false,
+                    flip_y: false,
+                    camera_entity,
+                    border: [0.; 4],
+                    border_radius: [0.; 4],
+                    node_type: NodeType::Rect,
+                }),
+        );
+    }
+
+    let section_offsets = char_colors.map(|_| section_offsets(&text.sections));
+    // a fresh iterator for every pass over the glyphs, effects drawn behind the glyphs are
+    // emitted in passes of their own so they're behind every glyph, not just their own
+    let glyph_quads = || {
+        let mut color = LinearRgba::from(Color::WHITE);
+        let mut current_section = usize::MAX;
+        let section_offsets = section_offsets.as_deref();
+        layout.glyphs.iter().enumerate().filter_map(
+            move |(
+                glyph_index,
+                PositionedGlyph {
+                    position,
+                    atlas_info,
+                    section_index,
+                    byte_index,
+                    ..
+                },
+            )| {
+                if *section_index != current_section {
+                    color = TextTint::apply(
+                        tint,
+                        LinearRgba::from(text.sections[*section_index].style.color),
+                    );
+                    color.alpha *= opacity;
+                    current_section = *section_index;
+                }
+                // the overrides that apply to the glyph are multiplied together
+                let override_color = [
+                    char_colors
+                        .zip(section_offsets)
+                        .and_then(|(char_colors, offsets)| {
+                            char_colors.color_at(
+                                offsets[*section_index] + *byte_index,
+                                offsets[offsets.len() - 1],
+                            )
+                        }),
+                    color_anim
+                        .filter(|anim| anim.animates(*section_index))
+                        .map(UiTextColorAnim::color),
+                    color_cycle.and_then(|cycle| cycle.color(glyph_index, time)),
+                    gradient
+                        .and_then(|gradient| gradient.color_for(*position, layout.logical_size)),
+                ]
+                .into_iter()
+                .flatten()
+                .map(|color| LinearRgba::from(color).to_vec4())
+                .reduce(|a, b| a * b)
+                .map(LinearRgba::from_vec4);
+                let glyph_color = if color_glyphs.is_some_and(|glyphs| glyphs.is_color(glyph_index))
+                {
+                    let mut color = TextTint::apply(tint, LinearRgba::WHITE);
+                    color.alpha *= opacity;
+                    color
+                } else if let Some(override_color) = override_color {
+                    let mut color = TextTint::apply(tint, override_color);
+                    color.alpha *= opacity;
+                    color
+                } else {
+                    color
+                };
+                // hidden by alpha rather than visibility, nothing to draw
+                if glyph_color.alpha <= 0. {
+                    return None;
+                }
+                let atlas = texture_atlases.get(&atlas_info.texture_atlas)?;
+
+                let mut rect = atlas.textures[atlas_info.glyph_index].as_rect();
+                rect.min *= inverse_scale_factor;
+                rect.max *= inverse_scale_factor;
+
+                let section_synthesis = synthesis
+                    .map(|synthesis| synthesis.section(*section_index))
+                    .unwrap_or_default();
+                let mut glyph_position = *position * inverse_scale_factor;
+                if let Some(wave) = wave {
+                    glyph_position += wave.offset(glyph_index, time);
+                }
+                let mut glyph_transform =
+                    transform * Mat4::from_translation(glyph_position.extend(0.));
+                if orientation == UiTextOrientation::Vertical {
+                    if let Some(rotation) =
+                        vertical_glyph_rotation(&text.sections, *section_index, *byte_index)
+                    {
+                        glyph_transform *= rotation;
+                    }
+                }
+                if section_synthesis.faux_italic {
+                    glyph_transform *= italic_shear;
+                }
+                Some(GlyphQuad {
+                    transform: glyph_transform,
+                    color: glyph_color,
+                    rect,
+                    image: atlas_info.texture.id(),
+                    atlas_size: atlas.size.as_vec2() * inverse_scale_factor,
+                    faux_bold: section_synthesis.faux_bold,
+                })
+            },
+        )
+    };
+    // the transforms of every copy of a glyph drawn to embolden it, and of the glyph itself
+    let passes = |quad: GlyphQuad| {
+        quad.faux_bold
+            .then(|| quad.transform * Mat4::from_translation(Vec3::X * bold_offset))
+            .into_iter()
+            .chain(
+                bold_offsets
+                    .iter()
+                    .map(move |offset| quad.transform * Mat4::from_translation(Vec3::X * *offset)),
+            )
+            .chain([quad.transform])
+    };
+    let glyph_node = |quad: GlyphQuad, transform: Mat4, color: LinearRgba| ExtractedUiNode {
+        stack_index,
+        transform,
+        color,
+        rect: quad.rect,
+        image: quad.image,
+        atlas_size: Some(quad.atlas_size),
+        clip,
+        flip_x: false,
+        flip_y: false,
+        camera_entity,
+        border: [0.; 4],
+        border_radius: [0.; 4],
+        node_type: NodeType::Rect,
+    };
+
+    if let Some(glow) = glow {
+        let glow_color = LinearRgba::from(glow.color);
+        nodes.extend(glyph_quads().flat_map(|quad| {
+            let color =
+                glow_color.with_alpha(glow_color.alpha * quad.color.alpha / GLOW_LAYERS as f32);
+            glow.layer_scales(quad.rect.size()).map(move |scale| {
+                glyph_node(
+                    quad,
+                    quad.transform * Mat4::from_scale(scale.extend(1.)),
+                    color,
+                )
+            })
+        }));
+    }
+    if let Some(shadow) = shadow {
+        let offset = Mat4::from_translation(shadow.offset.extend(0.));
+        let shadow_color = LinearRgba::from(shadow.color);
+        nodes.extend(glyph_quads().flat_map(|quad| {
+            let color = shadow_color.with_alpha(shadow_color.alpha * quad.color.alpha);
+            passes(quad).map(move |transform| glyph_node(quad, offset * transform, color))
+        }));
+    }
+    if let Some(outline) = outline {
+        let outline_color = LinearRgba::from(outline.color);
+        nodes.extend(glyph_quads().flat_map(|quad| {
+            let color = outline_color.with_alpha(outline_color.alpha * quad.color.alpha);
+            outline.offsets().flat_map(move |offset| {
+                let offset = Mat4::from_translation(offset.extend(0.));
+                passes(quad).map(move |transform| glyph_node(quad, offset * transform, color))
+            })
+        }));
+    }
+    nodes.extend(glyph_quads().flat_map(|quad| {
+        passes(quad).map(move |transform| glyph_node(quad, transform, quad.color))
+    }));
+
+    if let Some(decorations) = decorations {
+        // drawn over the glyphs, strikethroughs cross them
+        let rects = decorations.rects(&text.sections, &layout.glyphs, inverse_scale_factor.recip());
+        nodes.extend(rects.into_iter().map(|(section_index, rect)| {
+            let mut color = TextTint::apply(
+                tint,
+                LinearRgba::from(text.sections[section_index].style.color),
+            );
+            color.alpha *= opacity;
+            ExtractedUiNode {
+                stack_index,
+                transform: transform
+                    * Mat4::from_translation(rect.center().extend(0.) * inverse_scale_factor),
+                color,
+                rect: Rect::from_corners(Vec2::ZERO, rect.size() * inverse_scale_factor),
+                image: AssetId::default(),
+                atlas_size: None,
+                clip,
+                flip_x: false,
+                flip_y: false,
+                camera_entity,
+                border: [0.; 4],
+                border_radius: [0.; 4],
+                node_type: NodeType::Rect,
+            }
+        }));
+    }
+
+    if let Some(caret) = caret.filter(|caret| caret.visible) {
+        let caret_rect = caret.rect(glyphs.visible_size().y);
+        let mut color = LinearRgba::from(caret.color);
+        color.alpha *= opacity;
+        nodes.extend([ExtractedUiNode {
+            stack_index,
+            transform: transform * Mat4::from_translation(caret_rect.center().extend(0.)),
+            color,
+            rect: Rect::from_corners(Vec2::ZERO, caret_rect.size()),
+            image: AssetId::default(),
+            atlas_size: None,
+            clip,
+            flip_x: false,
+            flip_y: false,
+            camera_entity,
+            border: [0.; 4],
+            border_radius: [0.; 4],
+            node_type: NodeType::Rect,
+        }]);
+    }
+}
+
+/// A glyph ready to be converted into [`ExtractedUiNode`]s.
+#[derive(Clone, Copy)]
+struct GlyphQuad {
+    transform: Mat4,
+    color: LinearRgba,
+    rect: Rect,
+    image: AssetId<Image>,
+    atlas_size: Vec2,
+    faux_bold: bool,
+}
+
+/// Texts drawn with a custom material aren't extracted as glyph nodes.
+#[cfg(feature = "material")]
+type ExtractTextFilter = Without<UsesUiTextMaterial>;
+#[cfg(not(feature = "material"))]
+type ExtractTextFilter = ();
+
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn extract_text_sprite(
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    mut nodes: Local<Vec<ExtractedUiNode>>,
+    mut warned_without_camera: Local<EntityHashSet>,
+    texture_atlases: Extract<Res<Assets<TextureAtlasLayout>>>,
+    images: Extract<Res<Assets<Image>>>,
+    time: Extract<UiTextTime>,
+    mut commands: Commands,
+    extract_stats: Option<Res<UiTextExtractStats>>,
+    default_ui_camera: Extract<DefaultUiCamera>,
+    camera_query: Extract<Query<(&Camera, Option<&OrthographicProjection>)>>,
+    node_query: Extract<Query<&Node>>,
+    stack_index_fn: Extract<Res<UiTextStackIndexFn>>,
+    viewport_culling: Extract<Option<Res<UiTextViewportCulling>>>,
+    text_query: Extract<
+        Query<
+            (
+                Entity,
+                Ref<GlobalTransform>,
+                &UiText,
+                &ViewVisibility,
+                &TextLayoutInfo,
+                Option<&TargetCamera>,
+                Option<&UiTextScaling>,
+                Option<&UiTextSectionSynthesis>,
+                Option<&LabelLod>,
+                Option<&UiTextOrientation>,
+                Option<&UiTextLayoutScale>,
+                (
+                    Option<&TextTint>,
+                    Option<&TextSelection>,
+                    Option<&UiTextImageBackground>,
+                    Option<&ScreenOffset>,
+                    Option<&WaveText>,
+                    Option<&MinPixelSize>,
+                    Option<&InheritedUiTextOpacity>,
+                    Option<&SyntheticBold>,
+                    Option<&UiTextColorGlyphs>,
+                    Option<&HorizontalScroll>,
+                    Option<&UiTextBounds>,
+                    Option<&UiTextCaret>,
+                    Option<&Anchor>,
+                    Option<(&UiTextKeepUpright, &Transform)>,
+                    Option<&TextSnapping>,
+                ),
+                (
+                    Option<&UiTextBakedImage>,
+                    Option<&UiTextCharColors>,
+                    Option<&UiTextBackground>,
+                    Option<&RequireCamera>,
+                    Option<&ColorCycle>,
+                    Option<&RelativeDepth>,
+                    Option<&UiTextShadow>,
+                    Option<&UiTextOutline>,
+                    Option<&UiTextGlow>,
+                    Option<&UiTextGradient>,
+                    Option<&UiTextDecorations>,
+                    Option<&UiTextColorAnim>,
+                    Option<&UiTextBlink>,
+                    Option<&UiTextAlpha>,
+                ),
+            ),
+            ExtractTextFilter,
+        >,
+    >,
+) {
+    for (
+        entity,
+        global_transform,
+        text,
+        computed_visibility,
+        text_layout,
+        maybe_camera,
+        maybe_scaling,
+        maybe_synthesis,
+        maybe_lod,
+        maybe_orientation,
+        maybe_layout_scale,
+        (
+            maybe_tint,
+            maybe_selection,
+            maybe_background,
+            maybe_screen_offset,
+            maybe_wave,
+            maybe_min_size,
+            maybe_opacity,
+            maybe_synthetic_bold,
+            maybe_color_glyphs,
+            maybe_scroll,
+            maybe_bounds,
+            maybe_caret,
+            maybe_anchor,
+            maybe_keep_upright,
+            maybe_snapping,
+        ),
+        (
+            maybe_baked,
+            maybe_char_colors,
+            maybe_plate,
+            maybe_require_camera,
+            maybe_color_cycle,
+            maybe_relative_depth,
+            maybe_shadow,
+            maybe_outline,
+            maybe_glow,
+            maybe_gradient,
+            maybe_decorations,
+            maybe_color_anim,
+            maybe_blink,
+            maybe_alpha,
+        ),
+    ) in text_query.iter()
+    {
+        if !computed_visibility.get() {
+            continue;
+        }
+        if maybe_blink.is_some_and(|blink| !blink.is_on(time.elapsed_seconds())) {
+            continue;
+        }
+
+        // the layout hasn't been computed yet, e.g. while the font is loading, and its
+        // `logical_size` can't be used to align the text. Empty text inputs still draw their caret.
+        if text_layout.glyphs.is_empty() && !maybe_caret.is_some_and(|caret| caret.visible) {
+            continue;
+        }
+
+        let Some(camera_entity) = maybe_camera
+            .map(TargetCamera::entity)
+            .or(default_ui_camera.get())
+        else {
+            // once per entity until it finds a camera again
+            if warned_without_camera.insert(entity) {
+                maybe_require_camera
+                    .copied()
+                    .unwrap_or_default()
+                    .report_missing(entity);
+            }
+            continue;
+        };
+        warned_without_camera.remove(&entity);
+
+        let camera = camera_query.get(camera_entity).ok();
+        let scale_factor = camera
+            .and_then(|(c, _)| c.target_scaling_factor())
+            .unwrap_or(1.0);
+        if !is_valid_scale_factor(scale_factor) {
+            // degenerate camera, e.g. a zero sized render target during a resize
+            continue;
+        }
+
+        let projection = camera.and_then(|(_, projection)| projection);
+        if let Some(lod) = maybe_lod {
+            if !lod.is_visible(&global_transform, projection) {
+                continue;
+            }
+        }
+
+        // changed since the last extraction
+        let moved = global_transform.is_changed();
+        let global_transform = &drawn_text_transform(
+            &global_transform,
+            maybe_keep_upright,
+            maybe_min_size,
+            text,
+            projection,
+        );
+
+        let glyphs = UiTextGlyphs {
+            text,
+            layout: text_layout,
+            global_transform,
+            stack_index: ui_text_stack_index(
+                &stack_index_fn,
+                global_transform,
+                maybe_relative_depth,
+                &node_query,
+            ),
+            scaling: maybe_scaling.copied().unwrap_or_default(),
+            synthesis: maybe_synthesis,
+            synthetic_bold: maybe_synthetic_bold,
+            tint: maybe_tint,
+            char_colors: maybe_char_colors,
+            color_anim: maybe_color_anim,
+            color_cycle: maybe_color_cycle,
+            gradient: maybe_gradient,
+            color_glyphs: maybe_color_glyphs,
+            shadow: maybe_shadow,
+            outline: maybe_outline,
+            glow: maybe_glow,
+            decorations: maybe_decorations,
+            opacity: maybe_opacity.map_or(1., InheritedUiTextOpacity::get)
+                * maybe_alpha.map_or(1., |alpha| alpha.0),
+            selection: maybe_selection,
+            caret: maybe_caret,
+            orientation: maybe_orientation.copied().unwrap_or_default(),
+            anchor: maybe_anchor,
+            snap: maybe_snapping.copied().unwrap_or_default().snaps(moved),
+            screen_offset: maybe_screen_offset.map_or(Vec2::ZERO, |offset| offset.0),
+            wave: maybe_wave,
+            scroll: maybe_scroll.map_or(0., |scroll| scroll.0),
+            clip_width: scroll_clip_width(maybe_scroll, maybe_bounds),
+            time: time.elapsed_seconds(),
+            camera_entity,
+            scale_factor,
+            layout_scale_factor: maybe_layout_scale.map_or(scale_factor, UiTextLayoutScale::get),
+        };
+        if let (Some(culling), Some(viewport_size)) = (
+            viewport_culling.as_deref(),
+            camera.and_then(|(camera, _)| camera.logical_viewport_size()),
+        ) {
+            if culling.is_culled(glyphs.screen_rect(), viewport_size) {
+                continue;
+            }
+        }
+        if let Some(plate) = maybe_plate {
+            ui_text_background_nodes(plate, glyphs, &mut *nodes);
+        }
+        if let Some(background) = maybe_background {
+            if let Some(image) = images.get(&background.image) {
+                ui_text_image_background_nodes(
+                    background,
+                    image.size().as_vec2(),
+                    glyphs,
+                    &mut *nodes,
+                );
+            }
+        }
+        match maybe_baked.filter(|baked| images.contains(&baked.image)) {
+            Some(baked) => ui_text_baked_image_node(baked, glyphs, &mut *nodes),
+            None => ui_text_glyph_nodes(glyphs, &texture_atlases, &mut *nodes),
+        }
+    }
+
+    if let Some(stats) = extract_stats {
+        stats.set_extracted_nodes(nodes.len());
+    }
+
+    // the buffer is filled first so the map is grown at most once per frame
+    extracted_uinodes.uinodes.reserve(nodes.len());
+    for node in nodes.drain(..) {
+        extracted_uinodes
+            .uinodes
+            .insert(commands.spawn_empty().id(), node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout(width: f32) -> TextLayoutInfo {
+        TextLayoutInfo {
+            glyphs: Vec::new(),
+            logical_size: Vec2::new(width, 20.),
+        }
+    }
+
+    #[test]
+    fn scroll_clips_to_the_width_of_finite_bounds() {
+        let scroll = HorizontalScroll(10.);
+        let bounds = UiTextBounds {
+            size: Vec2::new(50., 20.),
+        };
+        assert_eq!(scroll_clip_width(Some(&scroll), Some(&bounds)), Some(50.));
+        assert_eq!(scroll_clip_width(None, Some(&bounds)), None);
+        assert_eq!(
+            scroll_clip_width(Some(&scroll), Some(&UiTextBounds::UNBOUNDED)),
+            None
+        );
+    }
+
+    #[test]
+    fn glyphs_are_moved_by_the_clamped_scroll() {
+        let text = Text::default();
+        let layout = layout(100.);
+        let global_transform = GlobalTransform::IDENTITY;
+        let glyphs = UiTextGlyphs {
+            scroll: 80.,
+            clip_width: Some(50.),
+            ..UiTextGlyphs::new(&text, &layout, &global_transform, Entity::PLACEHOLDER, 1.)
+        };
+        let (box_transform, _) = glyphs.transform();
+        let (glyph_transform, _) = glyphs.glyph_transform();
+        assert_eq!(
+            glyph_transform.translation - box_transform.translation,
+            Vec3::new(-50., 0., 0.).into()
+        );
+        assert_eq!(glyphs.visible_size(), Vec2::new(50., 20.));
+    }
+
+    #[test]
+    fn unscrolled_glyphs_are_drawn_with_the_box_transform() {
+        let text = Text::default();
+        let layout = layout(30.);
+        let global_transform = GlobalTransform::from_xyz(5., 6., 0.);
+        let glyphs = UiTextGlyphs {
+            scroll: 10.,
+            clip_width: Some(50.),
+            ..UiTextGlyphs::new(&text, &layout, &global_transform, Entity::PLACEHOLDER, 2.)
+        };
+        assert_eq!(glyphs.glyph_transform(), glyphs.transform());
+    }
+
+    #[test]
+    fn small_texts_are_scaled_up_to_the_min_pixel_size() {
+        let text = Text::from_section(
+            "a",
+            TextStyle {
+                font_size: 4.,
+                ..default()
+            },
+        );
+        let global_transform = GlobalTransform::IDENTITY;
+        let drawn = drawn_text_transform(
+            &global_transform,
+            None,
+            Some(&MinPixelSize(8.)),
+            &text,
+            None,
+        );
+        assert_eq!(drawn.compute_transform().scale, Vec3::splat(2.));
+        let unscaled = drawn_text_transform(&global_transform, None, None, &text, None);
+        assert_eq!(unscaled, global_transform);
+    }
+}
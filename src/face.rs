@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use crate::UiText;
+
+/// Turns a [`UiText`] so its baseline points toward a point on the screen, e.g. the center of a
+/// radial menu.
+///
+/// The point is in the same logical UI pixels as the text's [`Transform`], with the origin at
+/// the top left of the window. Only the rotation around the z axis is replaced, the text keeps
+/// its tilt and its rotation is left alone while it's on the point. The text's translation is
+/// used as its position, so it shouldn't have a parent. Texts to the left of the point end up
+/// upside down, turn them by half a turn to keep them readable.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FaceScreenPoint(pub Vec2);
+
+pub fn face_ui_text_screen_points(
+    mut text_query: Query<(&FaceScreenPoint, &mut Transform), With<UiText>>,
+) {
+    for (point, mut transform) in &mut text_query {
+        let direction = point.0 - transform.translation.truncate();
+        if direction.length_squared() < f32::EPSILON {
+            continue;
+        }
+        // UI coordinates point down, so a positive angle turns the text clockwise on screen
+        let angle = direction.y.atan2(direction.x);
+        let (x, y, _) = transform.rotation.to_euler(EulerRot::XYZ);
+        let rotation = Quat::from_euler(EulerRot::XYZ, x, y, angle);
+        if transform.rotation != rotation {
+            transform.rotation = rotation;
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use ab_glyph::Font as _;
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+use bevy::text::TextLayoutInfo;
+
+/// Fonts used, in order, for characters missing from a section's own font,
+/// such as emoji or CJK characters mixed into Latin text.
+///
+/// Each character is looked up in the section's font first and then in each fallback font
+/// until one of them has a glyph for it. Sections are split into runs of characters sharing
+/// a font before layout, so a text with fallbacks costs a glyph lookup per font tried for every
+/// character, plus the extra runs laid out by the text pipeline. Lines mixing fonts are as tall
+/// as the largest ascent and descent of their fonts.
+///
+/// The text isn't laid out until every fallback font has loaded. Fallback fonts that failed to
+/// load are skipped.
+#[derive(Component, Clone, Debug, Default, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+pub struct FontFallback(pub Vec<Handle<Font>>);
+
+/// Fallback fonts for texts without a [`FontFallback`].
+#[derive(Resource, Clone, Debug, Default, Deref, DerefMut, Reflect)]
+#[reflect(Resource, Default)]
+pub struct DefaultFontFallback(pub Vec<Handle<Font>>);
+
+/// Whether any of the fallback fonts is still loading.
+pub(crate) fn fallbacks_loading(
+    fallbacks: &[Handle<Font>],
+    fonts: &Assets<Font>,
+    asset_server: &AssetServer,
+) -> bool {
+    fallbacks.iter().any(|handle| {
+        !fonts.contains(handle)
+            && matches!(
+                asset_server.get_load_state(handle),
+                Some(LoadState::Loading | LoadState::NotLoaded)
+            )
+    })
+}
+
+/// Where a run of characters split off by [`split_fallback_runs`] came from.
+pub(crate) struct FallbackRun {
+    section_index: usize,
+    byte_offset: usize,
+}
+
+/// Splits the sections into runs of characters that are drawn with the same font.
+pub(crate) fn split_fallback_runs(
+    sections: &[TextSection],
+    fallbacks: &[Handle<Font>],
+    fonts: &Assets<Font>,
+) -> (Vec<TextSection>, Vec<FallbackRun>) {
+    let mut runs = Vec::new();
+    let mut origins = Vec::new();
+    for (section_index, section) in sections.iter().enumerate() {
+        let mut push_run = |start: usize, end: usize, font: &Handle<Font>| {
+            let mut style = section.style.clone();
+            style.font = font.clone();
+            runs.push(TextSection {
+                value: section.value[start..end].to_string(),
+                style,
+            });
+            origins.push(FallbackRun {
+                section_index,
+                byte_offset: start,
+            });
+        };
+        let mut current: Option<(usize, &Handle<Font>)> = None;
+        for (byte_index, c) in section.value.char_indices() {
+            let font = font_for_char(c, &section.style.font, fallbacks, fonts);
+            match current {
+                Some((_, current_font)) if current_font == font => {}
+                Some((start, current_font)) => {
+                    push_run(start, byte_index, current_font);
+                    current = Some((byte_index, font));
+                }
+                None => current = Some((byte_index, font)),
+            }
+        }
+        if let Some((start, font)) = current {
+            push_run(start, section.value.len(), font);
+        }
+    }
+    (runs, origins)
+}
+
+pub(crate) fn font_for_char<'a>(
+    c: char,
+    primary: &'a Handle<Font>,
+    fallbacks: &'a [Handle<Font>],
+    fonts: &Assets<Font>,
+) -> &'a Handle<Font> {
+    if c.is_whitespace() || c.is_control() {
+        return primary;
+    }
+    std::iter::once(primary)
+        .chain(fallbacks)
+        .find(|handle| {
+            fonts
+                .get(*handle)
+                .is_some_and(|font| font.font.glyph_id(c).0 != 0)
+        })
+        .unwrap_or(primary)
+}
+
+/// Maps the section and byte indices of glyphs laid out from fallback runs
+/// back to the text's original sections.
+pub(crate) fn remap_fallback_glyphs(layout: &mut TextLayoutInfo, runs: &[FallbackRun]) {
+    for glyph in &mut layout.glyphs {
+        let run = &runs[glyph.section_index];
+        glyph.section_index = run.section_index;
+        glyph.byte_index += run.byte_offset;
+    }
+}
@@ -0,0 +1,159 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{IndependentTextBundle, TextTint, UiText, UiTextTime};
+
+/// How the opacity of a [`UiTextFloating`] text changes over its lifetime.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FloatingFade {
+    /// Stays opaque until it's despawned.
+    None,
+    /// Fades out at a constant rate.
+    #[default]
+    Linear,
+    /// Fades out slowly at first, then quickly.
+    EaseIn,
+    /// Fades out quickly at first, then slowly.
+    EaseOut,
+}
+
+impl FloatingFade {
+    /// Opacity after `t`, the fraction of the lifetime that has passed.
+    pub fn alpha(self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match self {
+            FloatingFade::None => 1.,
+            FloatingFade::Linear => 1. - t,
+            FloatingFade::EaseIn => 1. - t * t,
+            FloatingFade::EaseOut => (1. - t) * (1. - t),
+        }
+    }
+}
+
+/// Moves a text across the screen, fades it out through its [`TextTint`], and despawns it and
+/// its descendants at the end of its lifetime. For damage numbers, pickup notifications etc.
+///
+/// Spawn the text at a position projected with
+/// [`world_to_ui_text_position`](crate::world_to_ui_text_position) to float it up from a world
+/// entity.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextFloating {
+    /// Logical pixels per second, `y` points down.
+    pub velocity: Vec2,
+    /// Change in velocity per second.
+    pub gravity: Vec2,
+    pub lifetime: Duration,
+    pub fade: FloatingFade,
+    /// Largest random change to the initial velocity on each axis, so texts spawned together
+    /// don't overlap.
+    pub spread: Vec2,
+    age: Duration,
+    spread_applied: bool,
+}
+
+impl Default for UiTextFloating {
+    fn default() -> Self {
+        Self {
+            velocity: Vec2::new(0., -60.),
+            gravity: Vec2::ZERO,
+            lifetime: Duration::from_secs(1),
+            fade: FloatingFade::default(),
+            spread: Vec2::ZERO,
+            age: Duration::ZERO,
+            spread_applied: false,
+        }
+    }
+}
+
+impl UiTextFloating {
+    /// How long the text has been floating.
+    pub fn age(&self) -> Duration {
+        self.age
+    }
+}
+
+/// A floating damage number.
+#[derive(Bundle, Default)]
+pub struct DamageNumberBundle {
+    pub text: IndependentTextBundle,
+    pub floating: UiTextFloating,
+    pub tint: TextTint,
+}
+
+impl DamageNumberBundle {
+    /// A damage number at `translation`, rising and spreading out a little.
+    pub fn new(value: impl Into<String>, style: TextStyle, translation: Vec3) -> Self {
+        Self {
+            text: IndependentTextBundle {
+                text: UiText::from_section(value, style),
+                transform: Transform::from_translation(translation),
+                ..Default::default()
+            },
+            floating: UiTextFloating {
+                velocity: Vec2::new(0., -80.),
+                gravity: Vec2::new(0., 60.),
+                spread: Vec2::new(30., 20.),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+}
+
+/// A pseudo random number in `-1..=1` derived from the entity, so floating texts spawned in the
+/// same frame spread out differently.
+fn entity_noise(entity: Entity, axis: u64) -> f32 {
+    // splitmix64
+    let mut x = entity.to_bits() ^ axis.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^= x >> 31;
+    (x >> 40) as f32 / (1u64 << 23) as f32 - 1.
+}
+
+pub fn update_floating_ui_texts(
+    mut commands: Commands,
+    time: UiTextTime,
+    mut text_query: Query<(
+        Entity,
+        &mut UiTextFloating,
+        &mut Transform,
+        Option<&mut TextTint>,
+    )>,
+) {
+    let delta = time.delta();
+    for (entity, mut floating, mut transform, maybe_tint) in &mut text_query {
+        if !floating.spread_applied {
+            let spread =
+                floating.spread * Vec2::new(entity_noise(entity, 0), entity_noise(entity, 1));
+            floating.velocity += spread;
+            floating.spread_applied = true;
+        }
+        floating.age += delta;
+        if floating.lifetime <= floating.age {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        let delta_seconds = delta.as_secs_f32();
+        let gravity = floating.gravity;
+        floating.velocity += gravity * delta_seconds;
+        transform.translation += (floating.velocity * delta_seconds).extend(0.);
+
+        let alpha = floating
+            .fade
+            .alpha(floating.age.as_secs_f32() / floating.lifetime.as_secs_f32());
+        match maybe_tint {
+            Some(mut tint) => tint.0.set_alpha(alpha),
+            None => {
+                commands
+                    .entity(entity)
+                    .insert(TextTint(Color::WHITE.with_alpha(alpha)));
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+
+use crate::{world_to_ui_text_position, UiText};
+
+/// What happens to a [`FollowEntity`] label when its target is despawned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FollowTargetLost {
+    /// Despawns the label and its descendants.
+    #[default]
+    Despawn,
+    /// Hides the label with its [`Visibility`] and removes its [`FollowEntity`].
+    Hide,
+}
+
+/// Keeps a [`UiText`] at the on screen position of another entity, e.g. a nameplate above a
+/// sprite.
+///
+/// Every frame the target's world translation plus `offset` is projected into the viewport of the
+/// text's camera with [`world_to_ui_text_position`], which also sets the label's depth. The label
+/// keeps its last position while the point is outside the camera's depth range. The label
+/// shouldn't have a parent, its [`GlobalTransform`] is updated after transform propagation so it
+/// doesn't lag a frame behind its target.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct FollowEntity {
+    pub target: Entity,
+    /// Offset from the target's translation in world space.
+    pub offset: Vec3,
+    pub on_target_lost: FollowTargetLost,
+}
+
+impl FollowEntity {
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            offset: Vec3::ZERO,
+            on_target_lost: FollowTargetLost::default(),
+        }
+    }
+
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_on_target_lost(mut self, on_target_lost: FollowTargetLost) -> Self {
+        self.on_target_lost = on_target_lost;
+        self
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn follow_ui_text_targets(
+    mut commands: Commands,
+    default_ui_camera: DefaultUiCamera,
+    camera_query: Query<(&Camera, &GlobalTransform), Without<UiText>>,
+    target_query: Query<&GlobalTransform, Without<UiText>>,
+    mut text_query: Query<
+        (
+            Entity,
+            &FollowEntity,
+            Option<&TargetCamera>,
+            &mut Transform,
+            &mut GlobalTransform,
+            &mut Visibility,
+        ),
+        With<UiText>,
+    >,
+) {
+    for (
+        entity,
+        follow,
+        maybe_target_camera,
+        mut transform,
+        mut global_transform,
+        mut visibility,
+    ) in &mut text_query
+    {
+        let Ok(target_transform) = target_query.get(follow.target) else {
+            match follow.on_target_lost {
+                FollowTargetLost::Despawn => commands.entity(entity).despawn_recursive(),
+                FollowTargetLost::Hide => {
+                    *visibility = Visibility::Hidden;
+                    commands.entity(entity).remove::<FollowEntity>();
+                }
+            }
+            continue;
+        };
+        let Some((camera, camera_transform)) = maybe_target_camera
+            .map(TargetCamera::entity)
+            .or(default_ui_camera.get())
+            .and_then(|camera_entity| camera_query.get(camera_entity).ok())
+        else {
+            continue;
+        };
+        let world_position = target_transform.translation() + follow.offset;
+        let Some(translation) = world_to_ui_text_position(camera, camera_transform, world_position)
+        else {
+            continue;
+        };
+        if transform.translation != translation {
+            transform.translation = translation;
+            *global_transform = GlobalTransform::from(*transform);
+        }
+    }
+}
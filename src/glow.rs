@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+
+/// Number of enlarged copies of each glyph a [`UiTextGlow`] is drawn with.
+pub const GLOW_LAYERS: usize = 4;
+
+/// Draws a soft halo in `color` behind the glyphs of a [`UiText`](crate::UiText), e.g. for sci-fi
+/// HUDs or highlighting the active objective.
+///
+/// The halo is made of [`GLOW_LAYERS`] translucent copies of every glyph, each enlarged around
+/// the glyph's center to extend up to `radius` logical pixels, in the text's local space, past
+/// its edges. The layers overlap, so the halo is most opaque next to the glyph and fades out
+/// towards `radius`. Its alpha is multiplied by the alpha of each glyph. The glow is drawn
+/// behind a [`UiTextShadow`](crate::UiTextShadow) and a [`UiTextOutline`](crate::UiTextOutline).
+/// Baked texts don't glow.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextGlow {
+    pub color: Color,
+    pub radius: f32,
+}
+
+impl Default for UiTextGlow {
+    fn default() -> Self {
+        Self {
+            color: Color::srgba(0.4, 0.8, 1., 0.5),
+            radius: 6.,
+        }
+    }
+}
+
+impl UiTextGlow {
+    pub fn new(color: Color, radius: f32) -> Self {
+        Self { color, radius }
+    }
+
+    /// The scale of each layer's copy of a glyph of `size`, from the largest to the smallest.
+    pub fn layer_scales(&self, size: Vec2) -> impl Iterator<Item = Vec2> {
+        let radius = self.radius.max(0.);
+        (1..=GLOW_LAYERS).rev().map(move |layer| {
+            let grow = 2. * radius * layer as f32 / GLOW_LAYERS as f32;
+            (size + grow) / size.max(Vec2::splat(f32::EPSILON))
+        })
+    }
+}
@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+
+use crate::GradientDirection;
+
+/// Fills the glyphs of a [`UiText`](crate::UiText) with a gradient across its laid out block,
+/// instead of their sections' flat colors.
+///
+/// Each glyph takes the color at its center's position within the layout's logical size, from 0
+/// at the top or left edge to 1 at the bottom or right edge. `stops` are positions in that range
+/// with their colors, sorted by position and interpolated in linear color space, glyphs before
+/// the first or after the last stop take its color. Like a [`ColorCycle`](crate::ColorCycle) it
+/// doesn't relayout the text. The colors of [`UiTextCharColors`](crate::UiTextCharColors), a
+/// [`ColorCycle`](crate::ColorCycle) or a [`UiTextColorAnim`](crate::UiTextColorAnim) are
+/// multiplied with the gradient, and [`TextTint`](crate::TextTint) and opacity are multiplied
+/// into the colors.
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextGradient {
+    pub direction: GradientDirection,
+    pub stops: Vec<(f32, Color)>,
+}
+
+impl UiTextGradient {
+    /// A gradient from `start` at one edge to `end` at the other.
+    pub fn new(direction: GradientDirection, start: Color, end: Color) -> Self {
+        Self {
+            direction,
+            stops: vec![(0., start), (1., end)],
+        }
+    }
+
+    /// Adds a stop, keeping the stops sorted.
+    pub fn with_stop(mut self, position: f32, color: Color) -> Self {
+        let index = self.stops.partition_point(|(stop, _)| *stop <= position);
+        self.stops.insert(index, (position, color));
+        self
+    }
+
+    /// The color at `t`, `None` without stops.
+    pub fn color_at(&self, t: f32) -> Option<Color> {
+        let (first, last) = (self.stops.first()?, self.stops.last()?);
+        if t <= first.0 {
+            return Some(first.1);
+        }
+        let Some(index) = self.stops.iter().position(|(stop, _)| t < *stop) else {
+            return Some(last.1);
+        };
+        let (start, start_color) = self.stops[index - 1];
+        let (end, end_color) = self.stops[index];
+        let factor = if end > start {
+            (t - start) / (end - start)
+        } else {
+            0.
+        };
+        Some(
+            LinearRgba::from(start_color)
+                .mix(&LinearRgba::from(end_color), factor)
+                .into(),
+        )
+    }
+
+    /// The color of a glyph centered at `position` in a layout of `size`, in the layout's pixels.
+    pub fn color_for(&self, position: Vec2, size: Vec2) -> Option<Color> {
+        let t = match self.direction {
+            GradientDirection::Vertical => position.y / size.y,
+            GradientDirection::Horizontal => position.x / size.x,
+        };
+        self.color_at(if t.is_finite() { t } else { 0. })
+    }
+}
@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+
+use crate::UiText;
+
+/// The visibility group of a [`UiText`], shown and hidden together through the
+/// [`UiTextVisibilityGroups`] resource.
+///
+/// Texts without the component belong to [`UiTextGroup::DEFAULT`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextGroup(pub u32);
+
+impl UiTextGroup {
+    pub const DEFAULT: Self = Self(0);
+
+    /// A group identified by a name instead of a number, e.g. `UiTextGroup::named("debug")`.
+    ///
+    /// The id is a hash of the name, so the same name always gives the same group.
+    pub const fn named(name: &str) -> Self {
+        // FNV-1a
+        let bytes = name.as_bytes();
+        let mut hash = 0x811c_9dc5u32;
+        let mut i = 0;
+        while i < bytes.len() {
+            hash ^= bytes[i] as u32;
+            hash = hash.wrapping_mul(0x0100_0193);
+            i += 1;
+        }
+        Self(hash)
+    }
+}
+
+/// Hides every [`UiText`] at once, or the texts of disabled [`UiTextGroup`]s, e.g. for a
+/// screenshot mode.
+///
+/// Hidden texts keep their [`Visibility`], they're drawn again as soon as their group is
+/// enabled. Changes take effect the same frame if made before [`PostUpdate`].
+#[derive(Resource, Clone, Debug, Default)]
+pub struct UiTextVisibilityGroups {
+    /// Hides all texts, whatever their group.
+    pub hide_all: bool,
+    disabled: HashSet<UiTextGroup>,
+}
+
+impl UiTextVisibilityGroups {
+    pub fn disable(&mut self, group: UiTextGroup) {
+        self.disabled.insert(group);
+    }
+
+    pub fn enable(&mut self, group: UiTextGroup) {
+        self.disabled.remove(&group);
+    }
+
+    pub fn set_enabled(&mut self, group: UiTextGroup, enabled: bool) {
+        if enabled {
+            self.enable(group);
+        } else {
+            self.disable(group);
+        }
+    }
+
+    /// Toggles the group, returning whether it's now enabled.
+    pub fn toggle(&mut self, group: UiTextGroup) -> bool {
+        let enabled = !self.is_enabled(group);
+        self.set_enabled(group, enabled);
+        enabled
+    }
+
+    /// Whether texts in the group are drawn, ignoring `hide_all`.
+    pub fn is_enabled(&self, group: UiTextGroup) -> bool {
+        !self.disabled.contains(&group)
+    }
+
+    /// Whether texts in the group are drawn.
+    pub fn is_visible(&self, group: UiTextGroup) -> bool {
+        !self.hide_all && self.is_enabled(group)
+    }
+}
+
+/// Hides the texts of disabled groups, without touching their [`Visibility`].
+pub fn hide_ui_text_groups(
+    groups: Res<UiTextVisibilityGroups>,
+    mut text_query: Query<(&mut ViewVisibility, Option<&UiTextGroup>), With<UiText>>,
+) {
+    if !groups.hide_all && groups.disabled.is_empty() {
+        return;
+    }
+    for (mut view_visibility, maybe_group) in &mut text_query {
+        if !groups.is_visible(maybe_group.copied().unwrap_or_default()) {
+            *view_visibility = ViewVisibility::HIDDEN;
+        }
+    }
+}
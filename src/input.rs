@@ -0,0 +1,355 @@
+use ab_glyph::{Font as _, ScaleFont as _};
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use bevy::window::PrimaryWindow;
+
+use crate::{
+    drawn_global_transform, normalize_bounds, text_transform, BaseFontSize, RelativeFontSize,
+    ScreenOffset, UiText, UiTextBounds, UiTextComputedSize, UiTextFontScale, UiTextKeepUpright,
+};
+
+/// Makes a [`UiText`] an editable single line text field.
+///
+/// While focused, typed characters are inserted at the caret and backspace, delete, home, end and
+/// the left and right arrow keys edit the value and move the caret. Enter sends a
+/// [`UiTextSubmitted`] event. Clicking an input with the left mouse button focuses it and
+/// unfocuses every other input, clicking elsewhere unfocuses them all.
+///
+/// The value is shown in the text's first section, replacing its contents, and the caret is drawn
+/// by a [`UiTextCaret`] on the same entity. Other sections are left alone. The input assumes a
+/// left justified text that isn't wrapped, use [`BreakLineOn::NoWrap`](bevy::text::BreakLineOn)
+/// and a [`HorizontalScroll`](crate::HorizontalScroll) for long values.
+///
+/// IME composition isn't supported.
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextInput {
+    pub focused: bool,
+    /// Maximum number of characters in the value.
+    pub max_length: Option<usize>,
+    /// Shows every character of the value as this character, for passwords.
+    pub mask: Option<char>,
+    value: String,
+    /// Caret position in characters.
+    caret: usize,
+}
+
+impl UiTextInput {
+    pub fn new(value: impl Into<String>) -> Self {
+        let value = value.into();
+        Self {
+            caret: value.chars().count(),
+            value,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    pub fn with_mask(mut self, mask: char) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// The real value, unmasked.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Replaces the value and moves the caret to its end.
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        self.value = value.into();
+        self.caret = self.value.chars().count();
+    }
+
+    /// Caret position in characters.
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+
+    /// The value as shown, masked if the input has a mask.
+    pub fn display_value(&self) -> String {
+        match self.mask {
+            Some(mask) => mask.to_string().repeat(self.value.chars().count()),
+            None => self.value.clone(),
+        }
+    }
+
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(char_index)
+            .map_or(self.value.len(), |(index, _)| index)
+    }
+
+    fn insert(&mut self, characters: &str) {
+        for c in characters.chars().filter(|c| !c.is_control()) {
+            if self
+                .max_length
+                .is_some_and(|max_length| max_length <= self.value.chars().count())
+            {
+                return;
+            }
+            let index = self.byte_index(self.caret);
+            self.value.insert(index, c);
+            self.caret += 1;
+        }
+    }
+
+    fn backspace(&mut self) {
+        if 0 < self.caret {
+            self.caret -= 1;
+            self.value.remove(self.byte_index(self.caret));
+        }
+    }
+
+    fn delete(&mut self) {
+        if self.caret < self.value.chars().count() {
+            self.value.remove(self.byte_index(self.caret));
+        }
+    }
+}
+
+/// A caret drawn between two characters of a [`UiText`]'s first section.
+///
+/// Kept in sync with a [`UiTextInput`] on the same entity, shown while the input is focused.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextCaret {
+    /// Position in characters.
+    pub index: usize,
+    pub visible: bool,
+    pub color: Color,
+    /// Width in logical pixels.
+    pub width: f32,
+    /// Offset of the caret from the left of the text and its height, in logical pixels.
+    offset: f32,
+    height: f32,
+}
+
+impl Default for UiTextCaret {
+    fn default() -> Self {
+        Self {
+            index: 0,
+            visible: true,
+            color: Color::WHITE,
+            width: 2.,
+            offset: 0.,
+            height: 0.,
+        }
+    }
+}
+
+#[cfg(feature = "render")]
+impl UiTextCaret {
+    /// The caret's rect in the text's local space, with the origin at the top left of the text.
+    pub(crate) fn rect(&self, text_height: f32) -> Rect {
+        let top = 0.5 * (text_height - self.height);
+        Rect::new(
+            self.offset,
+            top,
+            self.offset + self.width,
+            top + self.height,
+        )
+    }
+}
+
+/// Sent when Enter is pressed in a focused [`UiTextInput`].
+#[derive(Event, Clone, Debug, PartialEq, Eq)]
+pub struct UiTextSubmitted {
+    pub entity: Entity,
+    pub value: String,
+}
+
+/// Focuses the [`UiTextInput`] under the cursor when the left mouse button is pressed.
+#[allow(clippy::type_complexity)]
+pub fn focus_ui_text_inputs(
+    mouse: Res<ButtonInput<MouseButton>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut input_query: Query<(
+        &mut UiTextInput,
+        &GlobalTransform,
+        Option<&UiTextComputedSize>,
+        Option<&UiTextBounds>,
+        Option<&ScreenOffset>,
+        Option<&Anchor>,
+        Option<(&UiTextKeepUpright, &Transform)>,
+    )>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+    let Some(cursor) = window_query
+        .get_single()
+        .ok()
+        .and_then(Window::cursor_position)
+    else {
+        return;
+    };
+    for (
+        mut input,
+        global_transform,
+        maybe_size,
+        maybe_bounds,
+        maybe_screen_offset,
+        maybe_anchor,
+        maybe_keep_upright,
+    ) in &mut input_query
+    {
+        let mut size = maybe_size.map_or(Vec2::ZERO, |size| size.size);
+        if let Some(bounds) = maybe_bounds {
+            // scrolled inputs are only as wide as their bounds, empty inputs can still be clicked
+            let bounds = normalize_bounds(bounds.size);
+            if bounds.x.is_finite() {
+                size.x = bounds.x;
+            }
+        }
+        let transform = text_transform(
+            &drawn_global_transform(global_transform, maybe_keep_upright),
+            size,
+            maybe_anchor,
+            1.,
+            maybe_screen_offset.map_or(Vec2::ZERO, |offset| offset.0),
+            true,
+        );
+        let local = transform
+            .inverse()
+            .transform_point3(cursor.extend(0.))
+            .truncate();
+        let focused = Rect::from_corners(Vec2::ZERO, size).contains(local);
+        if input.focused != focused {
+            input.focused = focused;
+        }
+    }
+}
+
+/// Edits focused [`UiTextInput`]s with keyboard input, and shows their values and carets.
+pub fn update_ui_text_inputs(
+    mut commands: Commands,
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut submitted: EventWriter<UiTextSubmitted>,
+    mut input_query: Query<(
+        Entity,
+        &mut UiTextInput,
+        &mut UiText,
+        Option<&mut UiTextCaret>,
+    )>,
+) {
+    let events: Vec<&KeyboardInput> = keyboard_events
+        .read()
+        .filter(|event| event.state == ButtonState::Pressed)
+        .collect();
+    for (entity, mut input, mut text, maybe_caret) in &mut input_query {
+        if input.focused {
+            for event in &events {
+                match &event.logical_key {
+                    Key::Character(characters) => input.insert(characters),
+                    Key::Space => input.insert(" "),
+                    Key::Backspace => input.backspace(),
+                    Key::Delete => input.delete(),
+                    Key::Home => input.caret = 0,
+                    Key::End => input.caret = input.value.chars().count(),
+                    Key::ArrowLeft => input.caret = input.caret.saturating_sub(1),
+                    Key::ArrowRight => {
+                        input.caret = (input.caret + 1).min(input.value.chars().count());
+                    }
+                    Key::Enter => {
+                        submitted.send(UiTextSubmitted {
+                            entity,
+                            value: input.value.clone(),
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if input.is_changed() {
+            let display_value = input.display_value();
+            match text.sections.first_mut() {
+                Some(section) => {
+                    if section.value != display_value {
+                        section.value = display_value;
+                    }
+                }
+                None => text
+                    .sections
+                    .push(TextSection::new(display_value, TextStyle::default())),
+            }
+        }
+
+        let caret = UiTextCaret {
+            index: input.caret,
+            visible: input.focused,
+            ..Default::default()
+        };
+        match maybe_caret {
+            Some(mut current) => {
+                if current.index != caret.index {
+                    current.index = caret.index;
+                }
+                if current.visible != caret.visible {
+                    current.visible = caret.visible;
+                }
+            }
+            None => {
+                commands.entity(entity).insert(caret);
+            }
+        }
+    }
+}
+
+/// Measures where [`UiTextCaret`]s are drawn from the fonts of their texts' first sections.
+#[allow(clippy::type_complexity)]
+pub fn position_ui_text_carets(
+    fonts: Res<Assets<Font>>,
+    base_font_size: Res<BaseFontSize>,
+    mut caret_query: Query<(
+        &mut UiTextCaret,
+        Ref<UiText>,
+        Option<&RelativeFontSize>,
+        Option<Ref<UiTextFontScale>>,
+    )>,
+) {
+    for (mut caret, text, maybe_relative_size, maybe_font_scale) in &mut caret_query {
+        if !(caret.is_changed()
+            || text.is_changed()
+            || base_font_size.is_changed()
+            || maybe_font_scale.as_ref().is_some_and(Ref::is_changed))
+        {
+            continue;
+        }
+        let Some(section) = text.sections.first() else {
+            continue;
+        };
+        let Some(font) = fonts.get(&section.style.font) else {
+            continue;
+        };
+        let font_size = maybe_relative_size.map_or(section.style.font_size, |relative_size| {
+            base_font_size.0 * relative_size.0
+        }) * maybe_font_scale.map_or(1., |font_scale| font_scale.0);
+        let font = font.font.as_scaled(font_size);
+        let mut offset = 0.;
+        let mut previous = None;
+        for c in section.value.chars().take(caret.index) {
+            let glyph_id = font.glyph_id(c);
+            if let Some(previous) = previous {
+                offset += font.kern(previous, glyph_id);
+            }
+            offset += font.h_advance(glyph_id);
+            previous = Some(glyph_id);
+        }
+        let height = font.ascent() - font.descent();
+        if caret.offset != offset || caret.height != height {
+            caret.offset = offset;
+            caret.height = height;
+        }
+    }
+}
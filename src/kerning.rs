@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+use bevy::text::TextLayoutInfo;
+use bevy::utils::HashMap;
+
+use crate::bidi::joined_text;
+use crate::lines::glyph_lines;
+
+/// Extra spacing between pairs of adjacent characters of a [`UiText`](crate::UiText), in logical
+/// pixels, e.g. to fine tune a logotype style title without editing the font.
+///
+/// A pair's amount moves the second character and everything after it on the line, positive
+/// values to the right and negative ones to the left. Amounts add to the font's own kerning.
+/// The overrides are applied after layout, so lines aren't wrapped again and a centered or right
+/// justified line moves by the full amount instead of half or none of it. The layout's width
+/// grows or shrinks with its widest line. Meant for left to right horizontal texts.
+///
+/// ```
+/// # use bevy_mod_ui_independent_text::KerningOverrides;
+/// let kerning = KerningOverrides::default()
+///     .with_pair('A', 'V', -2.)
+///     .with_pair('T', 'o', -1.5);
+/// ```
+#[derive(Component, Clone, Debug, Default, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KerningOverrides(pub HashMap<(char, char), f32>);
+
+impl KerningOverrides {
+    pub fn with_pair(mut self, first: char, second: char, amount: f32) -> Self {
+        self.0.insert((first, second), amount);
+        self
+    }
+}
+
+/// Shifts the glyphs following each overridden pair of characters.
+///
+/// Applied while the glyphs are still in the order they were laid out in. Glyphs are only a pair
+/// when their characters are adjacent in the text, the text pipeline doesn't emit glyphs for
+/// whitespace.
+pub(crate) fn apply_kerning_overrides(
+    layout: &mut TextLayoutInfo,
+    sections: &[TextSection],
+    overrides: &KerningOverrides,
+    scale_factor: f32,
+) {
+    if overrides.is_empty() {
+        return;
+    }
+    let (text, section_offsets) = joined_text(sections);
+    let mut right_before = 0f32;
+    let mut right_after = 0f32;
+    for line in glyph_lines(&layout.glyphs) {
+        let glyphs = &mut layout.glyphs[line];
+        let mut shift = 0.;
+        let mut previous: Option<(usize, char)> = None;
+        for glyph in glyphs.iter_mut() {
+            let start = section_offsets[glyph.section_index] + glyph.byte_index;
+            let current = text.get(start..).and_then(|rest| rest.chars().next());
+            if let (Some((previous_end, previous)), Some(current)) = (previous, current) {
+                if previous_end == start {
+                    if let Some(amount) = overrides.get(&(previous, current)) {
+                        shift += amount * scale_factor;
+                    }
+                }
+            }
+            let right = glyph.position.x + 0.5 * glyph.size.x;
+            right_before = right_before.max(right);
+            right_after = right_after.max(right + shift);
+            glyph.position.x += shift;
+            previous = current.map(|current| (start + current.len_utf8(), current));
+        }
+    }
+    // keeps the space between the glyphs and the edge of the logical box, e.g. of a trailing
+    // space
+    layout.logical_size.x = (layout.logical_size.x + right_after - right_before).max(0.);
+}
@@ -1,24 +1,182 @@
-use bevy::text::{scale_value, TextLayoutInfo};
-use bevy::ui::RenderUiSystem;
-use bevy::utils::HashSet;
-use bevy::window::WindowScaleFactorChanged;
-use bevy::{prelude::*, text::TextPipeline};
-use bevy::{
-    render::view::{check_visibility, VisibilitySystems},
-    ui::ExtractedUiNodes,
+use std::borrow::Cow;
+use std::time::Duration;
+
+use bevy::ecs::system::SystemParam;
+use bevy::math::{Affine3A, Vec3A};
+use bevy::prelude::*;
+use bevy::render::camera::CameraUpdateSystem;
+use bevy::render::view::{check_visibility, VisibilitySystems};
+#[cfg(feature = "render")]
+use bevy::render::RenderApp;
+use bevy::sprite::Anchor;
+use bevy::text::{
+    scale_value, BreakLineOn, FontAtlasSets, Text2dBounds, TextLayoutInfo, TextPipeline,
+    TextSettings, YAxisOrientation,
 };
-use bevy::{render::Extract, text::TextSettings};
-use bevy::{render::RenderApp, text::FontAtlasSets};
-use bevy::{text::PositionedGlyph, ui::ExtractedUiNode};
-use bevy::{text::Text2dBounds, window::PrimaryWindow};
-use bevy::{text::YAxisOrientation, ui::NodeType};
+#[cfg(feature = "render")]
+use bevy::ui::RenderUiSystem;
+use bevy::utils::{HashSet, Instant};
+use bevy::window::{PrimaryWindow, WindowScaleFactorChanged};
+
+use crate::lines::glyph_lines;
+
+#[cfg(feature = "accessibility")]
+mod accessibility;
+#[cfg(feature = "animation")]
+mod animation;
+mod atlas;
+mod background;
+mod bake;
+mod bidi;
+mod blink;
+mod cache;
+mod camera;
+mod char_colors;
+mod color_anim;
+mod color_cycle;
+mod culling;
+#[cfg(feature = "debug")]
+mod debug;
+mod decorations;
+mod diagnostics;
+mod emoji;
+#[cfg(feature = "render")]
+mod extract;
+mod face;
+mod fallback;
+mod floating;
+mod follow;
+mod glow;
+mod gradient;
+mod group;
+mod input;
+mod kerning;
+mod lines;
+mod localization;
+mod lod;
+mod markup;
+#[cfg(feature = "material")]
+mod material;
+mod max_lines;
+mod opacity;
+mod orientation;
+mod outline;
+mod pool;
+mod preload;
+mod projection;
+mod rasterize;
+mod relative_bounds;
+mod relative_depth;
+mod sampling;
+mod scene;
+mod scramble;
+mod scroll;
+mod selection;
+#[cfg(feature = "serde")]
+mod serialize;
+mod shadow;
+mod snapping;
+#[cfg(feature = "style_assets")]
+mod style;
+mod synthesis;
+mod tabs;
+mod time;
+mod timer;
+mod tint;
+mod tooltip;
+mod ui_node;
+mod upright;
+mod wave;
+
+#[cfg(feature = "accessibility")]
+pub use accessibility::*;
+pub use atlas::*;
+pub use background::*;
+pub use bake::*;
+pub use bidi::*;
+pub use blink::*;
+pub use cache::*;
+pub use camera::*;
+pub use char_colors::*;
+pub use color_anim::*;
+pub use color_cycle::*;
+pub use culling::*;
+#[cfg(feature = "debug")]
+pub use debug::*;
+pub use decorations::*;
+pub use diagnostics::*;
+pub use emoji::*;
+#[cfg(feature = "render")]
+pub use extract::*;
+pub use face::*;
+pub use fallback::*;
+pub use floating::*;
+pub use follow::*;
+pub use glow::*;
+pub use gradient::*;
+pub use group::*;
+pub use input::*;
+pub use kerning::*;
+pub use lines::iter_lines;
+pub use localization::*;
+pub use lod::*;
+pub use markup::*;
+#[cfg(feature = "material")]
+pub use material::*;
+pub use max_lines::*;
+pub use opacity::*;
+pub use orientation::*;
+pub use outline::*;
+pub use pool::*;
+pub use preload::*;
+pub use projection::*;
+pub use rasterize::*;
+pub use relative_bounds::*;
+pub use relative_depth::*;
+pub use sampling::*;
+pub use scene::*;
+pub use scramble::*;
+pub use scroll::*;
+pub use selection::*;
+#[cfg(feature = "serde")]
+pub use serialize::*;
+pub use shadow::*;
+pub use snapping::*;
+#[cfg(feature = "style_assets")]
+pub use style::*;
+pub use synthesis::*;
+pub use tabs::*;
+pub use time::*;
+pub use timer::*;
+pub use tint::*;
+pub use tooltip::*;
+pub use ui_node::*;
+pub use upright::*;
+pub use wave::*;
+
+/// Moves a [`UiText`] by a number of logical pixels when it's drawn, e.g. to nudge overlapping
+/// labels apart.
+///
+/// The offset is added after the text's [`GlobalTransform`], so it isn't rotated or scaled with
+/// the text, and before the text's position is snapped to the physical pixel grid, so offset
+/// texts stay crisp. Changing it doesn't relayout the text.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScreenOffset(pub Vec2);
 
 /// Newtype wrapper for [`Text`]
 ///
 /// Required so that the text isn't also extracted by `extract_text2d_sprite`
 /// and consequently drawn twice.
+///
+/// Entities with a `Text2dBundle` are laid out and drawn by Bevy as usual next to entities with
+/// a [`UiText`], see the `text2d_alongside` example. A single entity can't have both, Bevy's
+/// layout would overwrite the [`TextLayoutInfo`] the [`UiText`] is drawn from and the text
+/// would be drawn twice, which is warned about.
 #[derive(Clone, Component, Default, Debug, Deref, DerefMut, Reflect)]
-#[reflect(Component)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub struct UiText(pub Text);
 
 impl From<Text> for UiText {
@@ -49,6 +207,101 @@ impl UiText {
             style,
         });
     }
+
+    /// Sets the horizontal alignment of the text's lines.
+    ///
+    /// When called through [`Mut<UiText>`] this marks the text as changed and it will be relaid out.
+    pub fn set_justify(&mut self, justify: JustifyText) {
+        self.justify = justify;
+    }
+
+    /// Sets how the text is broken into lines when it overflows its bounds.
+    ///
+    /// When called through [`Mut<UiText>`] this marks the text as changed and it will be relaid out.
+    pub fn set_linebreak_behavior(&mut self, linebreak_behavior: BreakLineOn) {
+        self.linebreak_behavior = linebreak_behavior;
+    }
+
+    /// Sets the value of the section at `index`, only marking the text as changed if the value
+    /// is different.
+    ///
+    /// Returns whether the value changed, `false` if there is no section at `index`.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_ui_independent_text::UiText;
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// fn update_score_text(score: Res<Score>, mut query: Query<&mut UiText>) {
+    ///     for mut text in &mut query {
+    ///         // the text is only relaid out when the score changes
+    ///         UiText::set_section_value(&mut text, 0, &score.0.to_string());
+    ///     }
+    /// }
+    /// ```
+    pub fn set_section_value(this: &mut Mut<Self>, index: usize, value: &str) -> bool {
+        match this.sections.get(index) {
+            Some(section) if section.value != value => {
+                let section_value = &mut this.sections[index].value;
+                section_value.clear();
+                section_value.push_str(value);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Sets the color of the section at `index`, only marking the text as changed if the color
+    /// is different.
+    ///
+    /// Returns whether the color changed, `false` if there is no section at `index`.
+    pub fn set_section_color(this: &mut Mut<Self>, index: usize, color: Color) -> bool {
+        match this.sections.get(index) {
+            Some(section) if section.style.color != color => {
+                this.sections[index].style.color = color;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Sets the style of the section at `index`, only marking the text as changed if the style
+    /// is different.
+    ///
+    /// Returns whether the style changed, `false` if there is no section at `index`.
+    pub fn set_section_style(this: &mut Mut<Self>, index: usize, style: TextStyle) -> bool {
+        match this.sections.get(index) {
+            Some(section) if !styles_eq(&section.style, &style) => {
+                this.sections[index].style = style;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Edits a copy of the sections and writes it back, only marking the text as changed if
+    /// the sections are different.
+    ///
+    /// Returns whether the sections changed.
+    pub fn update_sections(this: &mut Mut<Self>, f: impl FnOnce(&mut Vec<TextSection>)) -> bool {
+        let mut sections = this.sections.clone();
+        f(&mut sections);
+        let unchanged = sections.len() == this.sections.len()
+            && sections
+                .iter()
+                .zip(&this.sections)
+                .all(|(a, b)| a.value == b.value && styles_eq(&a.style, &b.style));
+        if unchanged {
+            return false;
+        }
+        this.sections = sections;
+        true
+    }
+}
+
+/// [`TextStyle`] doesn't implement [`PartialEq`].
+fn styles_eq(a: &TextStyle, b: &TextStyle) -> bool {
+    a.font == b.font && a.font_size == b.font_size && a.color == b.color
 }
 
 /// Bundle of components needed to draw text to the Bevy UI
@@ -56,168 +309,930 @@ impl UiText {
 #[derive(Bundle, Default)]
 pub struct IndependentTextBundle {
     pub text: UiText,
-    pub text_2d_bounds: Text2dBounds,
+    pub bounds: UiTextBounds,
     pub transform: Transform,
     pub global_transform: GlobalTransform,
     pub visibility: Visibility,
     pub inherited_visibility: InheritedVisibility,
     pub view_visibility: ViewVisibility,
     pub text_layout: TextLayoutInfo,
+    pub font_paths: UiTextFontPaths,
+    pub layout_scale: UiTextLayoutScale,
+    pub computed_size: UiTextComputedSize,
+    pub metrics: TextMetrics,
+    /// The point of the text placed at its transform, the center by default.
+    pub text_anchor: Anchor,
+}
+
+/// Maximum size of the text in logical pixels.
+///
+/// Lines that are wider than the bounds are wrapped according to the text's `linebreak_behavior`:
+/// * [`BreakLineOn::WordBoundary`] breaks lines between words, a single word wider than the
+///   bounds is left on its own line and overflows.
+/// * [`BreakLineOn::AnyCharacter`] breaks lines between any two characters, even mid-word.
+/// * [`BreakLineOn::NoWrap`] ignores the width of the bounds, lines are only broken at explicit
+///   newlines.
+///
+/// A width or height of `f32::MAX`, as in [`Text2dBounds::UNBOUNDED`], is unbounded like
+/// [`UiTextBounds::UNBOUNDED`], and lays the text out exactly as if it had no bounds.
+///
+/// A [`Text2dBounds`] on the text is still used, with a deprecation warning, while its
+/// `UiTextBounds` is unbounded.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextBounds {
+    pub size: Vec2,
+}
+
+impl UiTextBounds {
+    /// Unbounded text, lines are only broken at explicit newlines.
+    pub const UNBOUNDED: Self = Self {
+        size: Vec2::splat(f32::INFINITY),
+    };
+
+    pub const fn new(width: f32, height: f32) -> Self {
+        Self {
+            size: Vec2::new(width, height),
+        }
+    }
+}
+
+impl Default for UiTextBounds {
+    fn default() -> Self {
+        Self::UNBOUNDED
+    }
+}
+
+impl From<Text2dBounds> for UiTextBounds {
+    fn from(bounds: Text2dBounds) -> Self {
+        Self { size: bounds.size }
+    }
+}
+
+/// Replaces bound sizes of `f32::MAX` or more, like [`Text2dBounds::UNBOUNDED`], and NaNs with
+/// infinity, the sentinel the layout uses for unbounded text.
+///
+/// `f32::MAX` would be scaled to a large finite width at scale factors below 1, or overflow to
+/// infinity above 1, and lines would be justified against it. Normalizing first lays out text
+/// with such bounds exactly like text without bounds.
+pub(crate) fn normalize_bounds(size: Vec2) -> Vec2 {
+    Vec2::select(size.cmplt(Vec2::splat(f32::MAX)), size, Vec2::INFINITY)
+}
+
+/// The bounds a text is laid out in, `None` when it's unbounded. Axes without bounds are
+/// infinite.
+///
+/// The unbounded [`UiTextBounds`] of the bundle doesn't hide the [`Text2dBounds`] of texts that
+/// haven't been migrated yet.
+pub(crate) fn resolve_bounds(
+    ui_text_bounds: Option<&UiTextBounds>,
+    text_2d_bounds: Option<&Text2dBounds>,
+) -> Option<Vec2> {
+    let ui_text_bounds = ui_text_bounds
+        .map(|bounds| normalize_bounds(bounds.size))
+        .filter(|size| *size != Vec2::INFINITY);
+    if ui_text_bounds.is_some() {
+        return ui_text_bounds;
+    }
+    let text_2d_bounds = text_2d_bounds
+        .map(|bounds| normalize_bounds(bounds.size))
+        .filter(|size| *size != Vec2::INFINITY);
+    if text_2d_bounds.is_some() {
+        warn_once!(
+            "Text2dBounds is deprecated as the bounds of a UiText, use UiTextBounds instead."
+        );
+    }
+    text_2d_bounds
+}
+
+/// How a text is affected by the window's scale factor.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UiTextScaling {
+    /// Font sizes, bounds and translation are in logical pixels and scaled by the scale factor.
+    #[default]
+    Logical,
+    /// Font sizes, bounds and translation are in physical pixels, ignoring the scale factor.
+    /// On a display with a scale factor of 2 the text appears half the size of logical text.
+    Physical,
+}
+
+/// Stops the text from being relaid out, it keeps its last layout even if the [`UiText`] changes.
+///
+/// Changes made while frozen are laid out once the marker is removed.
+/// Useful for throttling texts that change every frame but only need to update occasionally.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FrozenLayout;
+
+/// Marks the text's [`TextLayoutInfo`] as supplied by the user, e.g. a layout cached from a
+/// despawned entity, so the text isn't laid out when it's spawned.
+///
+/// The layout is kept until the [`UiText`] changes, then the text is laid out as usual and the
+/// marker is removed. Other changes that would relayout the text, like a new scale factor,
+/// are ignored while the marker is present.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrecomputedLayout {
+    /// The scale factor the layout was computed at, from the [`UiTextLayoutScale`] of the
+    /// text it was taken from. Always `1` for texts with [`UiTextScaling::Physical`].
+    pub scale_factor: f32,
+}
+
+impl Default for PrecomputedLayout {
+    fn default() -> Self {
+        Self { scale_factor: 1. }
+    }
+}
+
+/// Marks texts laid out by the last run of [`update_ui_independent_text_layout`], find them with
+/// a `With<JustRelaidOut>` filter.
+///
+/// Inserted on every text given a new layout and removed from all of them at the start of the
+/// next run, so systems ordered after the layout system see the texts laid out this frame.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct JustRelaidOut;
+
+/// Base font size that [`RelativeFontSize`] is resolved against.
+///
+/// Changing it relayouts every text with a [`RelativeFontSize`].
+#[derive(Resource, Clone, Copy, Debug, Deref, DerefMut, Reflect)]
+#[reflect(Resource, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BaseFontSize(pub f32);
+
+impl Default for BaseFontSize {
+    fn default() -> Self {
+        Self(16.)
+    }
+}
+
+/// Sets the font size of every section to a multiple of the [`BaseFontSize`] resource,
+/// overriding the sizes set in the sections' [`TextStyle`]s.
+///
+/// `RelativeFontSize(1.5)` is equivalent to `1.5em` or `150%`.
+#[derive(Component, Clone, Copy, Debug, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelativeFontSize(pub f32);
+
+impl RelativeFontSize {
+    /// Font size as a percentage of the [`BaseFontSize`].
+    pub fn percent(percent: f32) -> Self {
+        Self(percent / 100.)
+    }
+}
+
+impl Default for RelativeFontSize {
+    fn default() -> Self {
+        Self(1.)
+    }
+}
+
+/// Multiplies the font size of every section, after any [`RelativeFontSize`].
+///
+/// Changing the scale relayouts the text, so unlike scaling its [`Transform`] the glyphs are
+/// rasterized at their new size and stay sharp, e.g. for a title growing into place.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextFontScale(pub f32);
+
+impl Default for UiTextFontScale {
+    fn default() -> Self {
+        Self(1.)
+    }
+}
+
+/// Smallest scale factor texts are laid out and extracted with.
+pub const MIN_SCALE_FACTOR: f32 = 1e-3;
+
+/// The scale factor a [`UiText`] was last laid out at, which its glyphs were rasterized at.
+///
+/// Inserted by [`update_ui_independent_text_layout`].
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct UiTextLayoutScale(pub(crate) f32);
+
+impl UiTextLayoutScale {
+    pub fn get(&self) -> f32 {
+        self.0
+    }
+}
+
+impl Default for UiTextLayoutScale {
+    fn default() -> Self {
+        Self(1.)
+    }
+}
+
+/// The size of a [`UiText`]'s laid out glyphs in logical pixels, whatever the scale factor of
+/// its window, and its number of lines.
+///
+/// Updated by [`update_ui_independent_text_layout`] after every successful layout, to zero for
+/// empty texts. This is the supported way to read a text's footprint, e.g. to size a
+/// neighboring element. Unlike [`TextLayoutInfo::logical_size`], which is in the pixels of
+/// the scale factor the text was laid out at.
+///
+/// The size is of the unscaled and unrotated text, it doesn't include the text's [`Transform`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct UiTextComputedSize {
+    pub size: Vec2,
+    pub lines: usize,
+}
+
+/// The number of glyphs and lines of a laid out [`UiText`], e.g. for debugging or to pick a
+/// size for its container, without iterating over its [`TextLayoutInfo`].
+///
+/// Updated by [`update_ui_independent_text_layout`] with its [`UiTextComputedSize`]. The lines
+/// are the lines with glyphs, empty lines and tabs aren't counted.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default)]
+pub struct TextMetrics {
+    pub glyphs: usize,
+    pub lines: usize,
+}
+
+/// Settings the texts are laid out with.
+///
+/// Every text is laid out by [`update_ui_independent_text_layout`] with Bevy's [`TextPipeline`],
+/// which adds the glyphs to the [`FontAtlasSets`] shared with Bevy's own text. The
+/// [`TextSettings`] passed to the pipeline are `text_settings` if set, otherwise Bevy's global
+/// `TextSettings` resource. Setting them here keeps the layouts of independent texts from being
+/// affected by changes other plugins make to the global settings.
+///
+/// Insert the resource before or after adding the plugin:
+///
+/// ```ignore
+/// app.insert_resource(UiTextLayoutSettings {
+///     text_settings: Some(TextSettings {
+///         soft_max_font_atlases: NonZeroUsize::new(4).unwrap(),
+///         allow_dynamic_font_size: false,
+///     }),
+/// })
+/// .add_plugins(IndependentTextPlugin);
+/// ```
+///
+/// A layout only depends on the fonts, the sections, the bounds and the scale factor, not on
+/// the order glyphs were added to the atlases, so the same text is laid out the same way in
+/// every run. Which atlas and index a glyph is stored at can differ between runs. Bevy 0.14's
+/// font atlases are always 512 pixels square, or larger for glyphs that don't fit, and can't be
+/// configured.
+#[derive(Resource, Default)]
+pub struct UiTextLayoutSettings {
+    pub text_settings: Option<TextSettings>,
+}
+
+/// Lays out sections the same way as a [`UiText`], without an entity or waiting for a frame,
+/// e.g. to measure a tooltip before deciding whether to show it.
+///
+/// Needs mutable access to the text pipeline and the font atlas resources, since the glyphs
+/// are added to the atlases like for any other layout. Inside a system
+/// [`UiTextLayoutPipeline::queue_text`] does the same with a single system parameter.
+/// `bounds` is in logical pixels, [`Vec2::INFINITY`] for unbounded text, while the returned
+/// layout is in physical pixels, divide its `logical_size` by `scale_factor` to get the size
+/// in logical pixels. Returns [`TextError::NoSuchFont`] until the fonts have loaded.
+#[allow(clippy::too_many_arguments)]
+pub fn layout_text_once(
+    text_pipeline: &mut TextPipeline,
+    fonts: &Assets<Font>,
+    sections: &[TextSection],
+    scale_factor: f32,
+    justify: JustifyText,
+    linebreak_behavior: BreakLineOn,
+    bounds: Vec2,
+    font_atlas_sets: &mut FontAtlasSets,
+    texture_atlases: &mut Assets<TextureAtlasLayout>,
+    textures: &mut Assets<Image>,
+    text_settings: &TextSettings,
+) -> Result<TextLayoutInfo, TextError> {
+    let bounds = normalize_bounds(bounds) * scale_factor;
+    let mut layout = text_pipeline.queue_text(
+        fonts,
+        sections,
+        scale_factor,
+        justify,
+        linebreak_behavior,
+        bounds,
+        font_atlas_sets,
+        texture_atlases,
+        textures,
+        text_settings,
+        YAxisOrientation::TopToBottom,
+    )?;
+    justify_within_logical_box(&mut layout, justify, bounds.x);
+    Ok(layout)
+}
+
+/// The text pipeline and the assets and resources it lays out texts with.
+#[derive(SystemParam)]
+pub struct UiTextLayoutPipeline<'w> {
+    fonts: Res<'w, Assets<Font>>,
+    textures: ResMut<'w, Assets<Image>>,
+    texture_atlases: ResMut<'w, Assets<TextureAtlasLayout>>,
+    font_atlas_sets: ResMut<'w, FontAtlasSets>,
+    text_pipeline: ResMut<'w, TextPipeline>,
+    text_settings: Res<'w, TextSettings>,
+    layout_settings: Res<'w, UiTextLayoutSettings>,
+    cache: Option<ResMut<'w, UiTextLayoutCache>>,
+}
+
+impl UiTextLayoutPipeline<'_> {
+    pub fn fonts(&self) -> &Assets<Font> {
+        &self.fonts
+    }
+
+    /// Lays out the sections top to bottom, adding their glyphs to the font atlases.
+    ///
+    /// `bounds` is in physical pixels. Reuses a cached layout if there is a
+    /// [`UiTextLayoutCache`].
+    pub fn queue_text(
+        &mut self,
+        sections: &[TextSection],
+        scale_factor: f32,
+        justify: JustifyText,
+        linebreak_behavior: BreakLineOn,
+        bounds: Vec2,
+    ) -> Result<TextLayoutInfo, TextError> {
+        let Self {
+            fonts,
+            textures,
+            texture_atlases,
+            font_atlas_sets,
+            text_pipeline,
+            text_settings,
+            layout_settings,
+            cache,
+        } = self;
+        let text_settings = layout_settings
+            .text_settings
+            .as_ref()
+            .unwrap_or(text_settings);
+        let mut queue_text = || {
+            text_pipeline.queue_text(
+                fonts,
+                sections,
+                scale_factor,
+                justify,
+                linebreak_behavior,
+                bounds,
+                font_atlas_sets,
+                texture_atlases,
+                textures,
+                text_settings,
+                YAxisOrientation::TopToBottom,
+            )
+        };
+        match cache {
+            Some(cache) => cache.get_or_queue(
+                sections,
+                scale_factor,
+                justify,
+                linebreak_behavior,
+                bounds,
+                queue_text,
+            ),
+            None => queue_text(),
+        }
+    }
+
+    /// Adds the glyphs of `chars` in `font` at `font_size` to the font atlases ahead of time, so
+    /// the first texts using them don't stall the frame they're shown in.
+    ///
+    /// Glyphs are rasterized per physical size, so `scale_factor` must be the one the texts will
+    /// be laid out at, 1 for [`UiTextScaling::Physical`]. Returns [`TextError::NoSuchFont`]
+    /// until the font has loaded, so it can't just be called from a startup system. Add the
+    /// glyphs to [`UiTextGlyphPreloads`] there instead, to have them preloaded once the font
+    /// has loaded and again when the scale factor changes.
+    pub fn preload_glyphs(
+        &mut self,
+        font: &Handle<Font>,
+        font_size: f32,
+        chars: &str,
+        scale_factor: f32,
+    ) -> Result<(), TextError> {
+        let text_settings = self
+            .layout_settings
+            .text_settings
+            .as_ref()
+            .unwrap_or(&self.text_settings);
+        let sections = [TextSection::new(
+            chars,
+            TextStyle {
+                font: font.clone(),
+                font_size,
+                ..Default::default()
+            },
+        )];
+        // bypasses the layout cache, the layout itself isn't needed
+        self.text_pipeline.queue_text(
+            &self.fonts,
+            &sections,
+            scale_factor,
+            JustifyText::Left,
+            BreakLineOn::NoWrap,
+            Vec2::INFINITY,
+            &mut self.font_atlas_sets,
+            &mut self.texture_atlases,
+            &mut self.textures,
+            text_settings,
+            YAxisOrientation::TopToBottom,
+        )?;
+        Ok(())
+    }
 }
 
+/// The set of [`update_ui_independent_text_layout`] in [`PostUpdate`], to order systems reading
+/// the new layouts against it.
+///
+/// By default the layout only runs after [`CameraUpdateSystem`] and isn't ordered against
+/// bevy_ui's layout, so a [`UiTextComputedSize`] used to size a UI node only reaches the node
+/// the next frame. Ordering the set before [`UiSystem::Layout`](bevy::ui::UiSystem::Layout)
+/// makes the size available to the same frame's UI layout, at the cost of texts sized
+/// relative to a node with [`UiTextRelativeBounds`] using the node's size from the previous
+/// frame:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::ui::UiSystem;
+/// # use bevy_mod_ui_independent_text::UiTextLayoutSystem;
+/// # fn size_panel_to_text() {}
+/// App::new()
+///     .configure_sets(PostUpdate, UiTextLayoutSystem.before(UiSystem::Layout))
+///     .add_systems(
+///         PostUpdate,
+///         size_panel_to_text
+///             .after(UiTextLayoutSystem)
+///             .before(UiSystem::Layout),
+///     );
+/// ```
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UiTextLayoutSystem;
+
 #[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub fn update_ui_independent_text_layout(
+    mut commands: Commands,
     mut queue: Local<HashSet<Entity>>,
-    mut textures: ResMut<Assets<Image>>,
-    fonts: Res<Assets<Font>>,
-    windows: Query<&Window, With<PrimaryWindow>>,
+    mut pipeline: UiTextLayoutPipeline,
+    asset_server: Res<AssetServer>,
+    default_fallback: Res<DefaultFontFallback>,
+    (windows, default_ui_camera, camera_query): (
+        Query<&Window, With<PrimaryWindow>>,
+        DefaultUiCamera,
+        Query<&Camera>,
+    ),
     mut scale_factor_changed: EventReader<WindowScaleFactorChanged>,
-    mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
-    mut font_atlas_set_storage: ResMut<FontAtlasSets>,
-    mut text_pipeline: ResMut<TextPipeline>,
-    text_settings: Res<TextSettings>,
+    (
+        mut removed_scaling,
+        mut removed_font_scale,
+        mut removed_relative_size,
+        mut removed_bounds,
+        mut removed_synthesis,
+        mut removed_direction,
+        mut removed_orientation,
+    ): (
+        RemovedComponents<UiTextScaling>,
+        RemovedComponents<UiTextFontScale>,
+        RemovedComponents<RelativeFontSize>,
+        RemovedComponents<UiTextBounds>,
+        RemovedComponents<UiTextSectionSynthesis>,
+        RemovedComponents<UiTextDirection>,
+        RemovedComponents<UiTextOrientation>,
+    ),
+    (mut removed_tab_stops, mut removed_max_lines, mut removed_kerning, mut removed_fallback): (
+        RemovedComponents<TabStops>,
+        RemovedComponents<MaxLines>,
+        RemovedComponents<KerningOverrides>,
+        RemovedComponents<FontFallback>,
+    ),
+    base_font_size: Res<BaseFontSize>,
+    atlas_policy: Res<UiTextAtlasPolicy>,
+    mut text_errors: EventWriter<UiTextError>,
+    relaid_out_query: Query<Entity, With<JustRelaidOut>>,
+    mut layout_stats: Option<ResMut<UiTextLayoutStats>>,
     mut text_query: Query<(
         Entity,
         Ref<UiText>,
-        Option<&Text2dBounds>,
+        Option<Ref<UiTextBounds>>,
+        Option<Ref<Text2dBounds>>,
+        (Option<Ref<RelativeFontSize>>, Option<Ref<UiTextFontScale>>),
+        Option<Ref<UiTextScaling>>,
+        Option<Ref<FontFallback>>,
+        Option<Ref<UiTextSectionSynthesis>>,
+        Option<Ref<UiTextDirection>>,
+        (
+            Option<Ref<UiTextOrientation>>,
+            Option<Ref<TabStops>>,
+            Option<Ref<MaxLines>>,
+            Option<Ref<KerningOverrides>>,
+        ),
+        Option<Ref<TargetCamera>>,
+        (Has<FrozenLayout>, Option<Ref<PrecomputedLayout>>),
         &mut TextLayoutInfo,
+        Option<&mut UiTextLayoutScale>,
+        (Option<&mut UiTextComputedSize>, Option<&mut TextMetrics>),
     )>,
 ) {
+    let layout_start = layout_stats.is_some().then(Instant::now);
+    if let Some(stats) = &mut layout_stats {
+        stats.layout_time = Duration::ZERO;
+    }
+    for entity in &relaid_out_query {
+        commands.entity(entity).remove::<JustRelaidOut>();
+    }
     let factor_changed = scale_factor_changed.read().last().is_some();
     let scale_factor = match windows.get_single() {
-        Ok(window) => window.scale_factor(),
-        Err(_) => return,
+        Ok(window) if is_valid_scale_factor(window.scale_factor()) => window.scale_factor(),
+        _ => return,
     };
-    for (entity, ui_text, maybe_bounds, mut layout) in &mut text_query {
+    queue.extend(removed_scaling.read());
+    queue.extend(removed_font_scale.read());
+    queue.extend(removed_relative_size.read());
+    queue.extend(removed_bounds.read());
+    queue.extend(removed_synthesis.read());
+    queue.extend(removed_direction.read());
+    queue.extend(removed_orientation.read());
+    queue.extend(removed_tab_stops.read());
+    queue.extend(removed_max_lines.read());
+    queue.extend(removed_kerning.read());
+    queue.extend(removed_fallback.read());
+    for (
+        entity,
+        ui_text,
+        maybe_bounds,
+        maybe_text_2d_bounds,
+        (maybe_relative_size, maybe_font_scale),
+        maybe_scaling,
+        maybe_fallback,
+        maybe_synthesis,
+        maybe_direction,
+        (maybe_orientation, maybe_tab_stops, maybe_max_lines, maybe_kerning),
+        maybe_target_camera,
+        (frozen, maybe_precomputed),
+        mut layout,
+        maybe_layout_scale,
+        (maybe_computed_size, maybe_metrics),
+    ) in &mut text_query
+    {
         let UiText(text) = ui_text.as_ref();
-        if factor_changed || ui_text.is_changed() || queue.remove(&entity) {
-            let text_bounds = match maybe_bounds {
-                Some(bounds) => Vec2::new(
-                    scale_value(bounds.size.x, scale_factor),
-                    scale_value(bounds.size.y, scale_factor),
+        // the scale factor of the window the text is drawn to, which isn't the primary
+        // window in multi-window apps
+        let target_scale_factor = maybe_target_camera
+            .as_deref()
+            .map(TargetCamera::entity)
+            .or(default_ui_camera.get())
+            .and_then(|camera_entity| camera_query.get(camera_entity).ok())
+            .and_then(Camera::target_scaling_factor)
+            .filter(|scale_factor| is_valid_scale_factor(*scale_factor))
+            .unwrap_or(scale_factor);
+        let scale_factor = match maybe_scaling.as_deref() {
+            Some(UiTextScaling::Physical) => 1.,
+            _ => target_scale_factor,
+        };
+        let scaling_changed = maybe_scaling.as_ref().is_some_and(Ref::is_changed);
+        let fallback_changed = match &maybe_fallback {
+            Some(fallback) => fallback.is_changed(),
+            None => default_fallback.is_changed(),
+        };
+        let synthesis_changed = maybe_synthesis.as_ref().is_some_and(Ref::is_changed);
+        let direction_changed = maybe_direction.as_ref().is_some_and(Ref::is_changed);
+        let orientation_changed = maybe_orientation.as_ref().is_some_and(Ref::is_changed);
+        let tab_stops_changed = maybe_tab_stops.as_ref().is_some_and(Ref::is_changed);
+        let max_lines_changed = maybe_max_lines.as_ref().is_some_and(Ref::is_changed);
+        let kerning_changed = maybe_kerning.as_ref().is_some_and(Ref::is_changed);
+        let target_camera_changed = maybe_target_camera.as_ref().is_some_and(Ref::is_changed);
+        let bounds_changed = maybe_bounds.as_ref().is_some_and(Ref::is_changed)
+            || maybe_text_2d_bounds.as_ref().is_some_and(Ref::is_changed);
+        let relative_size_changed = maybe_relative_size
+            .as_ref()
+            .is_some_and(|relative_size| relative_size.is_changed() || base_font_size.is_changed());
+        let font_scale_changed = maybe_font_scale.as_ref().is_some_and(Ref::is_changed);
+        let changed = factor_changed
+            || ui_text.is_changed()
+            || bounds_changed
+            || relative_size_changed
+            || font_scale_changed
+            || scaling_changed
+            || fallback_changed
+            || synthesis_changed
+            || direction_changed
+            || orientation_changed
+            || tab_stops_changed
+            || max_lines_changed
+            || kerning_changed
+            || target_camera_changed;
+        if let Some(precomputed) = maybe_precomputed {
+            if precomputed.is_added() {
+                let layout_scale_factor = match maybe_scaling.as_deref() {
+                    Some(UiTextScaling::Physical) => target_scale_factor,
+                    _ => precomputed.scale_factor,
+                };
+                let computed_size = UiTextComputedSize {
+                    size: layout.logical_size / layout_scale_factor,
+                    lines: glyph_lines(&layout.glyphs).len(),
+                };
+                set_layout_outputs(
+                    &mut commands,
+                    entity,
+                    maybe_layout_scale,
+                    precomputed.scale_factor,
+                    (maybe_computed_size, maybe_metrics),
+                    computed_size,
+                    layout.glyphs.len(),
+                );
+                queue.remove(&entity);
+                continue;
+            }
+            if !ui_text.is_changed() {
+                continue;
+            }
+            commands.entity(entity).remove::<PrecomputedLayout>();
+        }
+        if frozen {
+            // relayout once the text is unfrozen
+            if changed {
+                queue.insert(entity);
+            }
+            continue;
+        }
+        if changed || queue.remove(&entity) {
+            let bounds = resolve_bounds(maybe_bounds.as_deref(), maybe_text_2d_bounds.as_deref());
+            let mut text_bounds = match bounds {
+                Some(size) => Vec2::new(
+                    scale_value(size.x, scale_factor),
+                    scale_value(size.y, scale_factor),
                 ),
-                None => Vec2::new(f32::MAX, f32::MAX),
+                // infinite rather than `f32::MAX`, as centered and right justified lines are
+                // aligned to finite bounds
+                None => Vec2::INFINITY,
             };
-            match text_pipeline.queue_text(
-                &fonts,
-                &text.sections,
-                scale_factor,
-                text.justify,
-                text.linebreak_behavior,
-                text_bounds,
-                &mut font_atlas_set_storage,
-                &mut texture_atlases,
-                &mut textures,
-                &text_settings,
-                YAxisOrientation::TopToBottom,
-            ) {
+            let orientation = maybe_orientation.as_deref().copied().unwrap_or_default();
+            if orientation == UiTextOrientation::Vertical {
+                // laid out in lines that are turned into columns afterwards
+                text_bounds = text_bounds.yx();
+            }
+            if text.linebreak_behavior == BreakLineOn::NoWrap {
+                // the text pipeline still wraps lines at the bounds with `NoWrap`,
+                // only explicit newlines should break lines
+                text_bounds.x = f32::INFINITY;
+            }
+            let sections = match maybe_relative_size {
+                Some(relative_size) => Cow::Owned(
+                    text.sections
+                        .iter()
+                        .map(|section| {
+                            let mut section = section.clone();
+                            section.style.font_size = base_font_size.0 * relative_size.0;
+                            section
+                        })
+                        .collect(),
+                ),
+                None => Cow::Borrowed(text.sections.as_slice()),
+            };
+            let sections = match maybe_font_scale.as_deref() {
+                Some(font_scale) if font_scale.0 != 1. => Cow::Owned(
+                    sections
+                        .iter()
+                        .map(|section| {
+                            let mut section = section.clone();
+                            section.style.font_size *= font_scale.0;
+                            section
+                        })
+                        .collect(),
+                ),
+                _ => sections,
+            };
+            if let Some(synthesis) = &maybe_synthesis {
+                if text_bounds.x.is_finite() {
+                    text_bounds.x = (text_bounds.x
+                        - synthesis.italic_overhang(&sections) * scale_factor)
+                        .max(0.);
+                }
+            }
+            let fallbacks = match &maybe_fallback {
+                Some(fallback) => fallback.as_slice(),
+                None => default_fallback.as_slice(),
+            };
+            if fallbacks_loading(fallbacks, pipeline.fonts(), &asset_server) {
+                queue.insert(entity);
+                continue;
+            }
+            let (sections, fallback_runs) = if fallbacks.is_empty() {
+                (sections, None)
+            } else {
+                let (runs, origins) = split_fallback_runs(&sections, fallbacks, pipeline.fonts());
+                (Cow::Owned(runs), Some(origins))
+            };
+            let direction = maybe_direction.as_deref().copied().unwrap_or_default();
+            let justify = direction.justify(&text.sections, text.justify);
+            let result = atlas_policy.queue_text(
+                entity,
+                &sections,
+                |sections| {
+                    pipeline.queue_text(
+                        sections,
+                        scale_factor,
+                        justify,
+                        text.linebreak_behavior,
+                        text_bounds,
+                    )
+                },
+                |error| {
+                    text_errors.send(error);
+                },
+            );
+            match result {
                 Err(TextError::NoSuchFont) => {
                     queue.insert(entity);
                 }
-                Err(e @ TextError::FailedToAddGlyph(_)) => {
-                    panic!("Fatal error when processing text: {}.", e);
-                }
-                Ok(text_layout_info) => {
-                    layout.logical_size = Vec2::new(
-                        scale_value(text_layout_info.logical_size.x, 1. / scale_factor),
-                        scale_value(text_layout_info.logical_size.y, 1. / scale_factor),
+                Err(TextError::FailedToAddGlyph(_)) => {}
+                Ok(mut text_layout_info) => {
+                    justify_within_logical_box(&mut text_layout_info, justify, text_bounds.x);
+                    if let Some(fallback_runs) = &fallback_runs {
+                        remap_fallback_glyphs(&mut text_layout_info, fallback_runs);
+                    }
+                    if let Some(max_lines) = &maybe_max_lines {
+                        truncate_lines(&mut text_layout_info, **max_lines);
+                    }
+                    if let Some(kerning) = &maybe_kerning {
+                        apply_kerning_overrides(
+                            &mut text_layout_info,
+                            &text.sections,
+                            kerning,
+                            scale_factor,
+                        );
+                    }
+                    // counted before the glyphs are reordered or turned into columns
+                    let lines = glyph_lines(&text_layout_info.glyphs).len();
+                    if let Some(tab_stops) = &maybe_tab_stops {
+                        apply_tab_stops(
+                            &mut text_layout_info,
+                            &text.sections,
+                            tab_stops,
+                            scale_factor,
+                        );
+                    }
+                    reorder_bidi_glyphs(&mut text_layout_info, &text.sections, direction);
+                    if orientation == UiTextOrientation::Vertical {
+                        rotate_layout_to_vertical(&mut text_layout_info, &text.sections);
+                    }
+                    let computed_size = UiTextComputedSize {
+                        // texts with `UiTextScaling::Physical` are laid out at a scale factor of 1
+                        size: text_layout_info.logical_size / target_scale_factor,
+                        lines,
+                    };
+                    *layout = text_layout_info;
+                    set_layout_outputs(
+                        &mut commands,
+                        entity,
+                        maybe_layout_scale,
+                        scale_factor,
+                        (maybe_computed_size, maybe_metrics),
+                        computed_size,
+                        layout.glyphs.len(),
                     );
-                    *layout = text_layout_info
                 }
             }
         }
     }
+    if let (Some(stats), Some(start)) = (&mut layout_stats, layout_start) {
+        stats.layout_time = start.elapsed();
+    }
 }
 
-#[allow(clippy::type_complexity)]
-pub fn extract_text_sprite(
-    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
-    texture_atlases: Extract<Res<Assets<TextureAtlasLayout>>>,
-    mut commands: Commands,
-    default_ui_camera: Extract<DefaultUiCamera>,
-    camera_query: Extract<Query<(Entity, &Camera)>>,
-    text_query: Extract<
-        Query<(
-            &GlobalTransform,
-            &UiText,
-            &ViewVisibility,
-            &TextLayoutInfo,
-            Option<&TargetCamera>,
-        )>,
-    >,
+/// Updates or inserts the components describing a text's new layout.
+fn set_layout_outputs(
+    commands: &mut Commands,
+    entity: Entity,
+    maybe_layout_scale: Option<Mut<UiTextLayoutScale>>,
+    scale_factor: f32,
+    (maybe_computed_size, maybe_metrics): (
+        Option<Mut<UiTextComputedSize>>,
+        Option<Mut<TextMetrics>>,
+    ),
+    computed_size: UiTextComputedSize,
+    glyphs: usize,
 ) {
-    for (global_transform, text, computed_visibility, text_layout, maybe_camera) in
-        text_query.iter()
-    {
-        if !computed_visibility.get() {
-            continue;
+    match maybe_layout_scale {
+        Some(mut layout_scale) => layout_scale.0 = scale_factor,
+        None => {
+            commands
+                .entity(entity)
+                .insert(UiTextLayoutScale(scale_factor));
+        }
+    }
+    match maybe_computed_size {
+        Some(mut size) => {
+            size.set_if_neq(computed_size);
+        }
+        None => {
+            commands.entity(entity).insert(computed_size);
+        }
+    }
+    let metrics = TextMetrics {
+        glyphs,
+        lines: computed_size.lines,
+    };
+    match maybe_metrics {
+        Some(mut current) => {
+            current.set_if_neq(metrics);
         }
+        None => {
+            commands.entity(entity).insert(metrics);
+        }
+    }
+    commands.entity(entity).insert(JustRelaidOut);
+}
 
-        let Some(camera_entity) = maybe_camera
-            .map(TargetCamera::entity)
-            .or(default_ui_camera.get())
-        else {
-            continue;
-        };
+/// Warns about entities given both a [`UiText`] and a [`Text`], once when the second of the two
+/// is added.
+#[allow(clippy::type_complexity)]
+pub fn warn_ui_text_with_text_2d(
+    text_query: Query<Entity, (With<UiText>, With<Text>, Or<(Added<UiText>, Added<Text>)>)>,
+) {
+    for entity in &text_query {
+        warn!(
+            "{entity:?} has both a UiText and a Text, it will be drawn twice. Use separate entities."
+        );
+    }
+}
 
-        let scale_factor = camera_query
-            .get(camera_entity)
-            .ok()
-            .and_then(|(_, c)| c.target_scaling_factor())
-            .unwrap_or(1.0);
-        let inverse_scale_factor = scale_factor.recip();
+/// Zero, negative and non-finite scale factors would produce NaNs when inverted.
+pub(crate) fn is_valid_scale_factor(scale_factor: f32) -> bool {
+    scale_factor.is_finite() && MIN_SCALE_FACTOR <= scale_factor
+}
 
-        let text_glyphs = &text_layout.glyphs;
-        let (width, height) = (text_layout.logical_size.x, text_layout.logical_size.y);
-        let alignment_offset = -Vec2::new(width, height) * (Vec2::splat(0.5));
+/// Moves the glyphs of a new layout into its logical box, from `0` to `logical_size.x`.
+///
+/// The text pipeline justifies lines against the width of the bounds, or against the widest
+/// line for unbounded text, while the layout's `logical_size` only covers the glyphs. Without
+/// this a centered or right justified line in bounds wider than the text would be drawn off to
+/// the right of its [`Anchor`]. Afterwards justification only positions the lines within the
+/// box, and the anchor only positions the box.
+pub(crate) fn justify_within_logical_box(
+    layout: &mut TextLayoutInfo,
+    justify: JustifyText,
+    bounds_x: f32,
+) {
+    let factor = match justify {
+        JustifyText::Left => return,
+        JustifyText::Center => 0.5,
+        JustifyText::Right => 1.,
+    };
+    // the same offset the text pipeline aligns the lines to, both are floored so glyphs
+    // stay on whole pixels
+    let limit = if bounds_x.is_finite() {
+        bounds_x
+    } else {
+        layout.logical_size.x
+    };
+    let shift = (factor * layout.logical_size.x).floor() - (factor * limit).floor();
+    if shift != 0. {
+        for glyph in &mut layout.glyphs {
+            glyph.position.x += shift;
+        }
+    }
+}
+
+/// The point of a text's logical box of the given size that is placed at its transform,
+/// measured from the box's top left corner.
+pub(crate) fn anchor_point(anchor: Option<&Anchor>, logical_size: Vec2) -> Vec2 {
+    let anchor = anchor.map_or(Vec2::ZERO, Anchor::as_vec);
+    // anchors point up, UI coordinates point down
+    logical_size * (Vec2::splat(0.5) + Vec2::new(anchor.x, -anchor.y))
+}
+
+/// Transform from the text's local space, with its origin at the top left corner of the
+/// text's layout, to UI coordinates. `logical_size` is the size of the layout in the pixels of
+/// the text's local space, its `anchor` point is placed at the text's transform. The
+/// translation, including the `screen_offset`, is snapped to the physical pixel grid if `snap`
+/// is set.
+pub(crate) fn text_transform(
+    global_transform: &GlobalTransform,
+    logical_size: Vec2,
+    anchor: Option<&Anchor>,
+    scale_factor: f32,
+    screen_offset: Vec2,
+    snap: bool,
+) -> Affine3A {
+    let alignment_offset = -anchor_point(anchor, logical_size);
 
-        let mut transform = global_transform.affine()
-            * bevy::math::Affine3A::from_translation(alignment_offset.extend(0.));
+    let mut transform =
+        global_transform.affine() * Affine3A::from_translation(alignment_offset.extend(0.));
 
+    transform.translation += Vec3A::from(screen_offset.extend(0.));
+    if snap {
         transform.translation *= scale_factor;
         transform.translation = transform.translation.round();
-        transform.translation *= inverse_scale_factor;
-
-        let mut color = LinearRgba::from(Color::WHITE);
-        let mut current_section = usize::MAX;
-        for PositionedGlyph {
-            position,
-            atlas_info,
-            section_index,
-            ..
-        } in text_glyphs
-        {
-            if *section_index != current_section {
-                color = LinearRgba::from(text.sections[*section_index].style.color);
-                current_section = *section_index;
-            }
-            let atlas = texture_atlases.get(&atlas_info.texture_atlas).unwrap();
-
-            let mut rect = atlas.textures[atlas_info.glyph_index].as_rect();
-            rect.min *= inverse_scale_factor;
-            rect.max *= inverse_scale_factor;
-
-            extracted_uinodes.uinodes.insert(
-                commands.spawn_empty().id(),
-                ExtractedUiNode {
-                    stack_index: global_transform.translation().z as u32,
-                    transform: transform
-                        * Mat4::from_translation(position.extend(0.) * inverse_scale_factor),
-                    color,
-                    rect,
-                    image: atlas_info.texture.id(),
-                    atlas_size: Some(atlas.size.as_vec2() * inverse_scale_factor),
-                    clip: None,
-                    flip_x: false,
-                    flip_y: false,
-                    camera_entity,
-                    border: [0.; 4],
-                    border_radius: [0.; 4],
-                    node_type: NodeType::Rect,
-                },
-            );
-        }
+        transform.translation *= scale_factor.recip();
     }
+    transform
 }
 
 pub struct IndependentTextPlugin;
@@ -225,18 +1240,178 @@ pub struct IndependentTextPlugin;
 impl Plugin for IndependentTextPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<UiText>()
-            .add_systems(PostUpdate, update_ui_independent_text_layout)
+            .register_type::<RelativeFontSize>()
+            .register_type::<UiTextFontScale>()
+            .register_type::<BaseFontSize>()
+            .register_type::<NearestFontSampling>()
+            .register_type::<UiTextFontPaths>()
+            .register_type::<UiTextBounds>()
+            .register_type::<UiTextRelativeBounds>()
+            .register_type::<UiTextScaling>()
+            .register_type::<FontFallback>()
+            .register_type::<DefaultFontFallback>()
+            .register_type::<FrozenLayout>()
+            .register_type::<PrecomputedLayout>()
+            .register_type::<JustRelaidOut>()
+            .register_type::<UiTextSectionSynthesis>()
+            .register_type::<UiTextDecorations>()
+            .register_type::<SyntheticBold>()
+            .register_type::<UiNodeTextOrigin>()
+            .register_type::<UiTextKey>()
+            .register_type::<TextTint>()
+            .register_type::<UiTextCharColors>()
+            .register_type::<UiTextShadow>()
+            .register_type::<UiTextOutline>()
+            .register_type::<UiTextGlow>()
+            .register_type::<ColorCycle>()
+            .register_type::<UiTextColorAnim>()
+            .register_type::<UiTextBlink>()
+            .register_type::<UiTextGradient>()
+            .register_type::<UiTextTooltip>()
+            .register_type::<TextSelection>()
+            .register_type::<UiTextBackground>()
+            .register_type::<UiTextImageBackground>()
+            .register_type::<UiTextBake>()
+            .register_type::<UiTextBakedImage>()
+            .register_type::<UiTextDirection>()
+            .register_type::<LabelLod>()
+            .register_type::<MinPixelSize>()
+            .register_type::<UiTextOrientation>()
+            .register_type::<TabStops>()
+            .register_type::<KerningOverrides>()
+            .register_type::<MaxLines>()
+            .register_type::<ScreenOffset>()
+            .register_type::<TextSnapping>()
+            .register_type::<UiTextKeepUpright>()
+            .register_type::<UiTextAtlasPolicy>()
+            .register_type::<WaveText>()
+            .register_type::<UiTextLayoutScale>()
+            .register_type::<UiTextComputedSize>()
+            .register_type::<TextMetrics>()
+            .register_type::<UiTextGroupOpacity>()
+            .register_type::<UiTextAlpha>()
+            .register_type::<UiTextGroup>()
+            .register_type::<HorizontalScroll>()
+            .register_type::<UiTextInput>()
+            .register_type::<UiTextTimer>()
+            .register_type::<UiTextTimeSource>()
+            .register_type::<UiTextScramble>()
+            .register_type::<UiTextCaret>()
+            .register_type::<InheritedUiTextOpacity>()
+            .register_type::<UiTextFloating>()
+            .register_type::<FollowEntity>()
+            .register_type::<FaceScreenPoint>()
+            .register_type::<RelativeDepth>()
+            .register_type::<UiTextPooled>()
+            .register_type::<RequireCamera>()
+            .register_type::<Text2dBounds>()
+            .register_type::<TextLayoutInfo>()
+            .init_resource::<BaseFontSize>()
+            .init_resource::<DefaultFontFallback>()
+            .init_resource::<UiTextAtlasPolicy>()
+            .init_resource::<UiTextLayoutSettings>()
+            .init_resource::<UiTextStackIndexFn>()
+            .init_resource::<UiTextTimeSource>()
+            .init_resource::<UiTextVisibilityGroups>()
+            .add_event::<LocaleChanged>()
+            .add_event::<UiTextError>()
+            .add_event::<UiTextSubmitted>()
+            .add_event::<UiTextTimerFinished>()
+            .add_event::<UiTextScrambleFinished>()
             .add_systems(
                 PostUpdate,
-                check_visibility::<With<UiText>>.in_set(VisibilitySystems::CheckVisibility),
+                (
+                    (
+                        insert_missing_ui_text_layouts,
+                        resolve_ui_text_font_paths,
+                        record_ui_text_font_paths,
+                    )
+                        .chain()
+                        .before(update_ui_independent_text_layout),
+                    localize_ui_text.before(update_ui_independent_text_layout),
+                    update_ui_text_timers.before(update_ui_independent_text_layout),
+                    update_ui_text_scrambles.before(update_ui_independent_text_layout),
+                    resolve_ui_text_relative_bounds
+                        .after(CameraUpdateSystem)
+                        .before(update_ui_independent_text_layout),
+                    (
+                        focus_ui_text_inputs,
+                        update_ui_text_inputs,
+                        position_ui_text_carets,
+                    )
+                        .chain()
+                        .before(update_ui_independent_text_layout),
+                    preload_ui_text_glyphs
+                        .run_if(resource_exists::<UiTextGlyphPreloads>)
+                        .before(update_ui_independent_text_layout),
+                    invalidate_ui_text_layout_cache
+                        .run_if(resource_exists::<UiTextLayoutCache>)
+                        .before(update_ui_independent_text_layout),
+                    update_ui_independent_text_layout
+                        .in_set(UiTextLayoutSystem)
+                        .after(CameraUpdateSystem),
+                    apply_nearest_font_sampling.after(update_ui_independent_text_layout),
+                    warn_invalid_ui_text_char_colors,
+                    warn_ui_text_with_text_2d,
+                    bake_ui_texts.after(update_ui_independent_text_layout),
+                ),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    position_ui_text_tooltips
+                        .after(update_ui_independent_text_layout)
+                        .before(TransformSystem::TransformPropagate),
+                    update_floating_ui_texts.before(TransformSystem::TransformPropagate),
+                    update_ui_text_color_anims,
+                    face_ui_text_screen_points.before(TransformSystem::TransformPropagate),
+                    release_pooled_ui_texts
+                        .run_if(resource_exists::<UiTextPool>)
+                        .before(VisibilitySystems::VisibilityPropagate),
+                    position_ui_node_texts.after(TransformSystem::TransformPropagate),
+                    follow_ui_text_targets
+                        .after(TransformSystem::TransformPropagate)
+                        .before(VisibilitySystems::VisibilityPropagate),
+                    propagate_ui_text_group_opacity,
+                ),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    check_visibility::<With<UiText>>.in_set(VisibilitySystems::CheckVisibility),
+                    hide_ui_text_tooltips.after(VisibilitySystems::CheckVisibility),
+                    hide_ui_text_groups.after(VisibilitySystems::CheckVisibility),
+                ),
             );
-        let render_app = match app.get_sub_app_mut(RenderApp) {
-            Some(render_app) => render_app,
-            None => return,
-        };
-        render_app.add_systems(
-            ExtractSchedule,
-            extract_text_sprite.after(RenderUiSystem::ExtractText),
+        #[cfg(feature = "color_emoji")]
+        app.add_systems(
+            PostUpdate,
+            detect_ui_text_color_glyphs.after(update_ui_independent_text_layout),
         );
+        #[cfg(feature = "accessibility")]
+        app.register_type::<UiTextA11yIgnore>().add_systems(
+            PostUpdate,
+            update_ui_text_accessibility
+                .after(update_ui_independent_text_layout)
+                .after(hide_ui_text_tooltips)
+                .after(hide_ui_text_groups),
+        );
+        #[cfg(feature = "style_assets")]
+        app.init_asset::<UiTextStyleAsset>()
+            .init_asset::<UiTextStyleSheet>()
+            .init_asset_loader::<UiTextStyleLoader>()
+            .register_type::<UiTextStyleRef>()
+            .add_systems(
+                PostUpdate,
+                apply_ui_text_styles.before(update_ui_independent_text_layout),
+            );
+        // without the render feature only the layout is built, e.g. for headless tests
+        #[cfg(feature = "render")]
+        if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app.add_systems(
+                ExtractSchedule,
+                extract_text_sprite.after(RenderUiSystem::ExtractText),
+            );
+        }
     }
 }
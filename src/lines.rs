@@ -0,0 +1,166 @@
+use std::ops::Range;
+
+use bevy::math::Rect;
+use bevy::sprite::Anchor;
+use bevy::text::{PositionedGlyph, TextLayoutInfo};
+
+use crate::anchor_point;
+
+/// Splits laid out glyphs into lines, returning the index range of each line's glyphs.
+///
+/// Glyph positions are the centers of the glyphs' bounding boxes and vary with the height of
+/// each glyph, so a line ends where the pen moves back to the left, or where the next glyph's
+/// top edge is below the bottom edge of every glyph of the line so far, e.g. for a right
+/// justified line shorter than the one above it. Meant for horizontal layouts, the glyphs of a
+/// column of a vertical text are each on their own line.
+pub(crate) fn glyph_lines(glyphs: &[PositionedGlyph]) -> Vec<Range<usize>> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut line_bottom = f32::NEG_INFINITY;
+    for (index, pair) in glyphs.windows(2).enumerate() {
+        line_bottom = line_bottom.max(pair[0].position.y + 0.5 * pair[0].size.y);
+        let next_top = pair[1].position.y - 0.5 * pair[1].size.y;
+        if pair[1].position.x < pair[0].position.x || line_bottom <= next_top {
+            lines.push(start..index + 1);
+            start = index + 1;
+            line_bottom = f32::NEG_INFINITY;
+        }
+    }
+    if start < glyphs.len() {
+        lines.push(start..glyphs.len());
+    }
+    lines
+}
+
+/// Each line of a laid out text with the bounding rect of its glyphs, e.g. for per line
+/// backgrounds or hover effects.
+///
+/// The rects are in the layout's pixels, with the origin at the text's `anchor` point and y
+/// pointing down. Divide them by the text's [`UiTextLayoutScale`](crate::UiTextLayoutScale) to
+/// get logical pixels. Lines without glyphs aren't yielded. Meant for horizontal texts, each
+/// glyph of a vertical text is yielded as its own line.
+pub fn iter_lines<'a>(
+    layout: &'a TextLayoutInfo,
+    anchor: Option<&Anchor>,
+) -> impl Iterator<Item = (usize, Rect)> + 'a {
+    let origin = anchor_point(anchor, layout.logical_size);
+    glyph_lines(&layout.glyphs)
+        .into_iter()
+        .enumerate()
+        .map(move |(index, line)| {
+            let rect = glyphs_rect(&layout.glyphs[line]);
+            (
+                index,
+                Rect::from_corners(rect.min - origin, rect.max - origin),
+            )
+        })
+}
+
+/// The union of the bounding boxes of the glyphs.
+pub(crate) fn glyphs_rect(glyphs: &[PositionedGlyph]) -> Rect {
+    glyphs
+        .iter()
+        .map(|glyph| Rect::from_center_size(glyph.position, glyph.size))
+        .reduce(|a, b| a.union(b))
+        .unwrap_or_default()
+}
+
+/// Approximates the baseline of a line as the most common bottom edge of its glyphs,
+/// most glyphs have no descender.
+pub(crate) fn line_baseline(glyphs: &[PositionedGlyph]) -> f32 {
+    let mut bottoms: Vec<i32> = glyphs
+        .iter()
+        .map(|glyph| (glyph.position.y + 0.5 * glyph.size.y).round() as i32)
+        .collect();
+    bottoms.sort_unstable();
+    bottoms
+        .chunk_by(|a, b| a == b)
+        .max_by_key(|run| run.len())
+        .map_or(0., |run| run[0] as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::Vec2;
+    use bevy::text::{GlyphAtlasInfo, PositionedGlyph};
+
+    use super::glyph_lines;
+
+    fn glyph(x: f32, y: f32, width: f32, height: f32) -> PositionedGlyph {
+        PositionedGlyph {
+            position: Vec2::new(x, y),
+            size: Vec2::new(width, height),
+            atlas_info: GlyphAtlasInfo {
+                texture_atlas: Default::default(),
+                texture: Default::default(),
+                glyph_index: 0,
+            },
+            section_index: 0,
+            byte_index: 0,
+        }
+    }
+
+    #[test]
+    fn empty_layout_has_no_lines() {
+        assert!(glyph_lines(&[]).is_empty());
+    }
+
+    #[test]
+    fn glyphs_of_different_heights_share_a_line() {
+        // "Ag," with a descender and a comma below the baseline
+        let glyphs = [
+            glyph(5., 10., 10., 16.),
+            glyph(15., 14., 8., 16.),
+            glyph(22., 18., 3., 5.),
+        ];
+        assert_eq!(glyph_lines(&glyphs), vec![0..3]);
+    }
+
+    #[test]
+    fn pen_moving_back_left_starts_a_line() {
+        // "ab\ncd"
+        let glyphs = [
+            glyph(5., 10., 8., 12.),
+            glyph(15., 10., 8., 12.),
+            glyph(5., 30., 8., 12.),
+            glyph(15., 30., 8., 12.),
+        ];
+        assert_eq!(glyph_lines(&glyphs), vec![0..2, 2..4]);
+    }
+
+    #[test]
+    fn right_justified_short_line_is_split() {
+        // right justified "ab\nc", the "c" is right of the "a"
+        let glyphs = [
+            glyph(5., 10., 8., 12.),
+            glyph(15., 10., 8., 12.),
+            glyph(15., 30., 8., 12.),
+        ];
+        assert_eq!(glyph_lines(&glyphs), vec![0..2, 2..3]);
+    }
+
+    #[test]
+    fn single_glyph_lines_at_the_same_x_are_split() {
+        // "a\nb\nc" with every glyph at the same x
+        let glyphs = [
+            glyph(5., 10., 8., 12.),
+            glyph(5., 30., 8., 12.),
+            glyph(5., 50., 8., 12.),
+        ];
+        assert_eq!(glyph_lines(&glyphs), vec![0..1, 1..2, 2..3]);
+    }
+
+    #[test]
+    fn wider_glyph_on_the_next_line_is_split() {
+        // "i\nW", the center of the "W" is right of the "i"
+        let glyphs = [glyph(2., 10., 3., 12.), glyph(8., 30., 16., 12.)];
+        assert_eq!(glyph_lines(&glyphs), vec![0..1, 1..2]);
+    }
+
+    #[test]
+    fn empty_lines_are_skipped() {
+        // "a\n\nb"
+        let glyphs = [glyph(5., 10., 8., 12.), glyph(5., 50., 8., 12.)];
+        assert_eq!(glyph_lines(&glyphs), vec![0..1, 1..2]);
+    }
+}
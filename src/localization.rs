@@ -0,0 +1,114 @@
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::UiText;
+
+/// Localization key resolved by the [`UiTextLocalizer`] into the first section of the
+/// entity's [`UiText`].
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextKey {
+    pub key: String,
+    pub args: HashMap<String, String>,
+}
+
+impl UiTextKey {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            args: HashMap::default(),
+        }
+    }
+
+    pub fn with_arg(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.args.insert(name.into(), value.into());
+        self
+    }
+}
+
+/// A localization backend, e.g. fluent, gettext or a `HashMap`.
+///
+/// Implemented for closures with the same signature as [`Localizer::localize`].
+pub trait Localizer: Send + Sync + 'static {
+    /// Returns the localized string for the key, or `None` if the key can't be resolved.
+    fn localize(&self, key: &str, args: &HashMap<String, String>) -> Option<String>;
+}
+
+impl<F> Localizer for F
+where
+    F: Fn(&str, &HashMap<String, String>) -> Option<String> + Send + Sync + 'static,
+{
+    fn localize(&self, key: &str, args: &HashMap<String, String>) -> Option<String> {
+        self(key, args)
+    }
+}
+
+/// Resolves [`UiTextKey`]s.
+#[derive(Resource)]
+pub struct UiTextLocalizer {
+    localizer: Box<dyn Localizer>,
+    /// Color used to draw the key of texts whose key can't be resolved.
+    pub missing_key_color: Color,
+}
+
+impl UiTextLocalizer {
+    pub fn new(localizer: impl Localizer) -> Self {
+        Self {
+            localizer: Box::new(localizer),
+            missing_key_color: Color::srgb(1., 0., 1.),
+        }
+    }
+
+    pub fn localize(&self, key: &str, args: &HashMap<String, String>) -> Option<String> {
+        self.localizer.localize(key, args)
+    }
+}
+
+/// Send this event after switching locales to resolve every [`UiTextKey`] again.
+#[derive(Event, Clone, Copy, Debug, Default)]
+pub struct LocaleChanged;
+
+/// Writes the resolved strings of changed [`UiTextKey`]s into their texts' first section.
+pub fn localize_ui_text(
+    localizer: Option<Res<UiTextLocalizer>>,
+    mut locale_changed: EventReader<LocaleChanged>,
+    mut missing_keys: Local<EntityHashMap<Color>>,
+    mut removed_texts: RemovedComponents<UiText>,
+    mut text_query: Query<(Entity, Ref<UiTextKey>, &mut UiText)>,
+) {
+    // a recycled entity mustn't get the color of a despawned text back
+    for entity in removed_texts.read() {
+        missing_keys.remove(&entity);
+    }
+    let Some(localizer) = localizer else {
+        return;
+    };
+    let resolve_all = locale_changed.read().last().is_some() || localizer.is_changed();
+    for (entity, key, mut text) in &mut text_query {
+        if !(resolve_all || key.is_changed()) {
+            continue;
+        }
+        if text.sections.is_empty() {
+            text.sections.push(TextSection::default());
+        }
+        let value = match localizer.localize(&key.key, &key.args) {
+            Some(value) => {
+                if let Some(color) = missing_keys.remove(&entity) {
+                    text.sections[0].style.color = color;
+                }
+                value
+            }
+            None => {
+                let color = &mut text.bypass_change_detection().sections[0].style.color;
+                missing_keys.entry(entity).or_insert(*color);
+                *color = localizer.missing_key_color;
+                key.key.clone()
+            }
+        };
+        if text.sections[0].value != value {
+            text.sections[0].value = value;
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+
+/// Hides a [`UiText`](crate::UiText) when it would be drawn smaller than `min_visible_scale`,
+/// e.g. to declutter dense maps when zoomed out.
+///
+/// The effective scale of a text is the mean of the x and y scale of its [`GlobalTransform`],
+/// divided by the [`OrthographicProjection::scale`] of its camera if it has one. Texts are drawn
+/// in UI space and don't shrink as the camera zooms out, so a label with a unit scale above a
+/// world entity has an effective scale of `0.5` once the camera's projection scale reaches `2`.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LabelLod {
+    pub min_visible_scale: f32,
+}
+
+impl Default for LabelLod {
+    fn default() -> Self {
+        Self {
+            min_visible_scale: 0.5,
+        }
+    }
+}
+
+impl LabelLod {
+    /// Whether a text with this transform drawn to a camera with this projection is visible.
+    pub fn is_visible(
+        &self,
+        global_transform: &GlobalTransform,
+        projection: Option<&OrthographicProjection>,
+    ) -> bool {
+        self.min_visible_scale <= effective_scale(global_transform, projection)
+    }
+}
+
+/// The scale a text is drawn at, see [`LabelLod`].
+pub(crate) fn effective_scale(
+    global_transform: &GlobalTransform,
+    projection: Option<&OrthographicProjection>,
+) -> f32 {
+    let scale = global_transform.affine().matrix3;
+    let mut effective_scale = 0.5 * (scale.x_axis.length() + scale.y_axis.length());
+    if let Some(projection) = projection {
+        effective_scale /= projection.scale;
+    }
+    effective_scale
+}
+
+/// Enlarges a [`UiText`](crate::UiText) when its largest font would be drawn smaller than this
+/// many logical pixels, so tiny labels stay legible however far the camera zooms out.
+///
+/// The drawn font size is the font size scaled by the text's effective scale, as described for
+/// [`LabelLod`]. Below the floor the whole text is scaled up around its anchor, so it no longer
+/// matches its [`GlobalTransform`] and may overlap the things it labels. This trades spatial
+/// accuracy for readability.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MinPixelSize(pub f32);
+
+impl Default for MinPixelSize {
+    fn default() -> Self {
+        Self(8.)
+    }
+}
+
+impl MinPixelSize {
+    /// The extra scale, at least `1`, that keeps the text's largest font at or above the floor.
+    pub fn scale(
+        &self,
+        text: &Text,
+        global_transform: &GlobalTransform,
+        projection: Option<&OrthographicProjection>,
+    ) -> f32 {
+        let font_size = text
+            .sections
+            .iter()
+            .map(|section| section.style.font_size)
+            .fold(0., f32::max);
+        let drawn_size = font_size * effective_scale(global_transform, projection);
+        if 0. < drawn_size && drawn_size < self.0 {
+            self.0 / drawn_size
+        } else {
+            1.
+        }
+    }
+}
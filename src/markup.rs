@@ -0,0 +1,542 @@
+use std::fmt;
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::{Synthesis, UiText, UiTextSectionSynthesis};
+
+/// Named colors and fonts that can be referenced from [`UiText::from_markup`].
+#[derive(Resource, Clone, Debug, Default)]
+pub struct UiTextStyleRegistry {
+    colors: HashMap<String, Color>,
+    fonts: HashMap<String, Handle<Font>>,
+}
+
+impl UiTextStyleRegistry {
+    /// Registers a color that can be used as a tag, e.g. `<red>..</red>`.
+    pub fn with_color(mut self, name: impl Into<String>, color: Color) -> Self {
+        self.insert_color(name, color);
+        self
+    }
+
+    /// Registers a font that can be used with `<font=name>..</font>`.
+    pub fn with_font(mut self, name: impl Into<String>, font: Handle<Font>) -> Self {
+        self.insert_font(name, font);
+        self
+    }
+
+    pub fn insert_color(&mut self, name: impl Into<String>, color: Color) {
+        self.colors.insert(name.into(), color);
+    }
+
+    pub fn insert_font(&mut self, name: impl Into<String>, font: Handle<Font>) {
+        self.fonts.insert(name.into(), font);
+    }
+
+    pub fn color(&self, name: &str) -> Option<Color> {
+        self.colors.get(name).copied()
+    }
+
+    pub fn font(&self, name: &str) -> Option<&Handle<Font>> {
+        self.fonts.get(name)
+    }
+}
+
+/// Error returned by [`UiText::from_markup`].
+///
+/// All offsets are byte offsets into the markup string.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MarkupError {
+    /// A tag that is neither a registered color nor `size=` or `font=`, nor `b` or `i` for
+    /// [`UiText::from_markup_with_synthesis`].
+    UnknownTag { name: String, offset: usize },
+    /// `font=` references a font missing from the registry.
+    UnknownFont { name: String, offset: usize },
+    /// `size=` value isn't a positive number.
+    InvalidSize { value: String, offset: usize },
+    /// A `<` without a matching `>`.
+    UnterminatedTag { offset: usize },
+    /// A closing tag that doesn't match the innermost open tag.
+    MismatchedClose {
+        expected: String,
+        found: String,
+        offset: usize,
+    },
+    /// A closing tag with no open tag.
+    UnexpectedClose { name: String, offset: usize },
+    /// An open tag that is never closed.
+    UnclosedTag { name: String, offset: usize },
+    /// A `\` at the end of the input.
+    TrailingEscape { offset: usize },
+}
+
+impl fmt::Display for MarkupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarkupError::UnknownTag { name, offset } => {
+                write!(f, "unknown tag `{name}` at byte {offset}")
+            }
+            MarkupError::UnknownFont { name, offset } => {
+                write!(f, "unknown font `{name}` at byte {offset}")
+            }
+            MarkupError::InvalidSize { value, offset } => {
+                write!(f, "invalid font size `{value}` at byte {offset}")
+            }
+            MarkupError::UnterminatedTag { offset } => {
+                write!(f, "unterminated tag at byte {offset}")
+            }
+            MarkupError::MismatchedClose {
+                expected,
+                found,
+                offset,
+            } => write!(
+                f,
+                "expected `</{expected}>` but found `</{found}>` at byte {offset}"
+            ),
+            MarkupError::UnexpectedClose { name, offset } => {
+                write!(
+                    f,
+                    "closing tag `</{name}>` at byte {offset} has no open tag"
+                )
+            }
+            MarkupError::UnclosedTag { name, offset } => {
+                write!(f, "tag `<{name}>` opened at byte {offset} is never closed")
+            }
+            MarkupError::TrailingEscape { offset } => {
+                write!(f, "trailing escape character at byte {offset}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MarkupError {}
+
+struct OpenTag {
+    name: String,
+    offset: usize,
+    style: TextStyle,
+    synthesis: Synthesis,
+}
+
+impl UiText {
+    /// Constructs a [`UiText`] from a string with inline style tags.
+    ///
+    /// Supported tags:
+    /// * `<name>..</name>` where `name` is a color in the registry
+    /// * `<size=24>..</size>`
+    /// * `<font=name>..</font>` where `name` is a font in the registry
+    ///
+    /// Tags can be nested. Use `\<` for a literal `<` and `\\` for a literal `\`. For `<b>` and
+    /// `<i>` tags use [`UiText::from_markup_with_synthesis`].
+    ///
+    /// ```ignore
+    /// let text = UiText::from_markup("Deal <red>25</red> damage", style, &registry)?;
+    /// ```
+    pub fn from_markup(
+        input: &str,
+        base_style: TextStyle,
+        registry: &UiTextStyleRegistry,
+    ) -> Result<UiText, MarkupError> {
+        parse_markup(input, base_style, registry, false)
+            .map(|(sections, _)| UiText::from_sections(sections))
+    }
+
+    /// Like [`UiText::from_markup`], also returning the faux bold and italic of the sections
+    /// inside `<b>..</b>` and `<i>..</i>` tags, so emphasis can be drawn with a single font.
+    ///
+    /// Insert the returned [`UiTextSectionSynthesis`] along with the text. The tags take
+    /// precedence over registry colors named `b` or `i`.
+    ///
+    /// ```ignore
+    /// let (text, synthesis) =
+    ///     UiText::from_markup_with_synthesis("A <b>bold</b> move", style, &registry)?;
+    /// commands.spawn((IndependentTextBundle { text, ..default() }, synthesis));
+    /// ```
+    pub fn from_markup_with_synthesis(
+        input: &str,
+        base_style: TextStyle,
+        registry: &UiTextStyleRegistry,
+    ) -> Result<(UiText, UiTextSectionSynthesis), MarkupError> {
+        parse_markup(input, base_style, registry, true).map(|(sections, synthesis)| {
+            (
+                UiText::from_sections(sections),
+                UiTextSectionSynthesis::new(synthesis),
+            )
+        })
+    }
+}
+
+/// Splits the markup into sections and the synthesis of each section, `<b>` and `<i>` are only
+/// tags with `synthesis_tags`.
+fn parse_markup(
+    input: &str,
+    base_style: TextStyle,
+    registry: &UiTextStyleRegistry,
+    synthesis_tags: bool,
+) -> Result<(Vec<TextSection>, Vec<Synthesis>), MarkupError> {
+    let mut sections: Vec<TextSection> = Vec::new();
+    let mut synthesis: Vec<Synthesis> = Vec::new();
+    let mut stack: Vec<OpenTag> = Vec::new();
+    let mut value = String::new();
+    let mut chars = input.char_indices();
+
+    while let Some((offset, c)) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some((_, escaped)) => value.push(escaped),
+                None => return Err(MarkupError::TrailingEscape { offset }),
+            },
+            '<' => {
+                let tag_start = offset + 1;
+                let tag_end = input[tag_start..]
+                    .find('>')
+                    .map(|end| tag_start + end)
+                    .ok_or(MarkupError::UnterminatedTag { offset })?;
+                let tag = &input[tag_start..tag_end];
+                // skip the tag's contents and the closing `>`
+                for _ in chars.by_ref().take_while(|&(i, _)| i < tag_end) {}
+
+                let (style, current_synthesis) = stack
+                    .last()
+                    .map_or((&base_style, Synthesis::default()), |open| {
+                        (&open.style, open.synthesis)
+                    });
+                if !value.is_empty() {
+                    sections.push(TextSection {
+                        value: std::mem::take(&mut value),
+                        style: style.clone(),
+                    });
+                    synthesis.push(current_synthesis);
+                }
+
+                if let Some(name) = tag.strip_prefix('/') {
+                    let name = name.trim();
+                    match stack.pop() {
+                        Some(open) if open.name == name => {}
+                        Some(open) => {
+                            return Err(MarkupError::MismatchedClose {
+                                expected: open.name,
+                                found: name.to_string(),
+                                offset,
+                            })
+                        }
+                        None => {
+                            return Err(MarkupError::UnexpectedClose {
+                                name: name.to_string(),
+                                offset,
+                            })
+                        }
+                    }
+                } else {
+                    let (name, style, tag_synthesis) = parse_tag(
+                        tag.trim(),
+                        style,
+                        current_synthesis,
+                        registry,
+                        offset,
+                        synthesis_tags,
+                    )?;
+                    stack.push(OpenTag {
+                        name,
+                        offset,
+                        style,
+                        synthesis: tag_synthesis,
+                    });
+                }
+            }
+            _ => value.push(c),
+        }
+    }
+
+    if let Some(open) = stack.pop() {
+        return Err(MarkupError::UnclosedTag {
+            name: open.name,
+            offset: open.offset,
+        });
+    }
+
+    if !value.is_empty() || sections.is_empty() {
+        sections.push(TextSection {
+            value,
+            style: base_style,
+        });
+        synthesis.push(Synthesis::default());
+    }
+
+    Ok((sections, synthesis))
+}
+
+/// Returns the tag's name, used to match the closing tag, and the style and synthesis it
+/// applies.
+fn parse_tag(
+    tag: &str,
+    current: &TextStyle,
+    current_synthesis: Synthesis,
+    registry: &UiTextStyleRegistry,
+    offset: usize,
+    synthesis_tags: bool,
+) -> Result<(String, TextStyle, Synthesis), MarkupError> {
+    let mut style = current.clone();
+    let emphasis = match tag {
+        "b" if synthesis_tags => Some(Synthesis::BOLD),
+        "i" if synthesis_tags => Some(Synthesis::ITALIC),
+        _ => None,
+    };
+    if let Some(emphasis) = emphasis {
+        return Ok((tag.to_string(), style, current_synthesis.union(emphasis)));
+    }
+    let (name, style) = match tag.split_once('=') {
+        Some(("size", value)) => {
+            let value = value.trim();
+            style.font_size = value
+                .parse::<f32>()
+                .ok()
+                .filter(|size| size.is_finite() && 0. < *size)
+                .ok_or_else(|| MarkupError::InvalidSize {
+                    value: value.to_string(),
+                    offset,
+                })?;
+            ("size".to_string(), style)
+        }
+        Some(("font", value)) => {
+            let value = value.trim();
+            style.font = registry
+                .font(value)
+                .ok_or_else(|| MarkupError::UnknownFont {
+                    name: value.to_string(),
+                    offset,
+                })?
+                .clone();
+            ("font".to_string(), style)
+        }
+        _ => {
+            style.color = registry.color(tag).ok_or_else(|| MarkupError::UnknownTag {
+                name: tag.to_string(),
+                offset,
+            })?;
+            (tag.to_string(), style)
+        }
+    };
+    Ok((name, style, current_synthesis))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn style() -> TextStyle {
+        TextStyle {
+            font_size: 20.,
+            color: Color::WHITE,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn synthesis_tags_are_registry_colors_without_synthesis() {
+        let registry = UiTextStyleRegistry::default().with_color("b", Color::BLACK);
+        let text = UiText::from_markup("a <b>bold</b> move", style(), &registry).unwrap();
+        assert_eq!(text.sections.len(), 3);
+        assert_eq!(text.sections[1].value, "bold");
+        assert_eq!(text.sections[1].style.color, Color::BLACK);
+    }
+
+    #[test]
+    fn synthesis_tags_are_unknown_without_synthesis() {
+        let registry = UiTextStyleRegistry::default();
+        assert_eq!(
+            UiText::from_markup("a <i>slanted</i> move", style(), &registry).unwrap_err(),
+            MarkupError::UnknownTag {
+                name: "i".to_string(),
+                offset: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn synthesis_tags_set_the_synthesis_of_their_sections() {
+        let registry = UiTextStyleRegistry::default().with_color("b", Color::BLACK);
+        let (text, synthesis) =
+            UiText::from_markup_with_synthesis("a <b>bold <i>and</i></b> move", style(), &registry)
+                .unwrap();
+        let values: Vec<&str> = text.sections.iter().map(|s| s.value.as_str()).collect();
+        assert_eq!(values, ["a ", "bold ", "and", " move"]);
+        assert_eq!(synthesis.section(0), Synthesis::default());
+        assert_eq!(synthesis.section(1), Synthesis::BOLD);
+        assert_eq!(synthesis.section(2), Synthesis::BOLD_ITALIC);
+        assert_eq!(synthesis.section(3), Synthesis::default());
+        // the tag takes precedence over the registry color
+        assert_eq!(text.sections[1].style.color, Color::WHITE);
+    }
+
+    fn values(text: &UiText) -> Vec<&str> {
+        text.sections
+            .iter()
+            .map(|section| section.value.as_str())
+            .collect()
+    }
+
+    fn parse_err(input: &str) -> MarkupError {
+        let registry = UiTextStyleRegistry::default().with_color("red", Color::BLACK);
+        UiText::from_markup(input, style(), &registry).unwrap_err()
+    }
+
+    #[test]
+    fn empty_input_is_one_empty_section() {
+        let text = UiText::from_markup("", style(), &UiTextStyleRegistry::default()).unwrap();
+        assert_eq!(values(&text), [""]);
+        assert_eq!(text.sections[0].style.font_size, 20.);
+    }
+
+    #[test]
+    fn plain_text_is_one_section_in_the_base_style() {
+        let text =
+            UiText::from_markup("no tags here", style(), &UiTextStyleRegistry::default()).unwrap();
+        assert_eq!(values(&text), ["no tags here"]);
+        assert_eq!(text.sections[0].style.color, Color::WHITE);
+    }
+
+    #[test]
+    fn nested_tags_combine_their_styles() {
+        let font = Handle::weak_from_u128(7);
+        let registry = UiTextStyleRegistry::default()
+            .with_color("red", Color::BLACK)
+            .with_font("mono", font.clone());
+        let text = UiText::from_markup(
+            "a <red>b <size=32>c <font=mono>d</font></size> e</red> f",
+            style(),
+            &registry,
+        )
+        .unwrap();
+        assert_eq!(values(&text), ["a ", "b ", "c ", "d", " e", " f"]);
+        let styles: Vec<(Color, f32, bool)> = text
+            .sections
+            .iter()
+            .map(|section| {
+                (
+                    section.style.color,
+                    section.style.font_size,
+                    section.style.font == font,
+                )
+            })
+            .collect();
+        assert_eq!(
+            styles,
+            [
+                (Color::WHITE, 20., false),
+                (Color::BLACK, 20., false),
+                (Color::BLACK, 32., false),
+                (Color::BLACK, 32., true),
+                (Color::BLACK, 20., false),
+                (Color::WHITE, 20., false),
+            ]
+        );
+    }
+
+    #[test]
+    fn tag_names_and_values_are_trimmed() {
+        let registry = UiTextStyleRegistry::default().with_color("red", Color::BLACK);
+        let text =
+            UiText::from_markup("<size= 24 >a</size >< red >b</ red>", style(), &registry).unwrap();
+        assert_eq!(values(&text), ["a", "b"]);
+        assert_eq!(text.sections[0].style.font_size, 24.);
+        assert_eq!(text.sections[1].style.color, Color::BLACK);
+    }
+
+    #[test]
+    fn escapes_are_literal_characters() {
+        let text = UiText::from_markup(r"1 \< 2 \\ 3 \>", style(), &UiTextStyleRegistry::default())
+            .unwrap();
+        assert_eq!(values(&text), [r"1 < 2 \ 3 >"]);
+    }
+
+    #[test]
+    fn multibyte_text_keeps_byte_offsets() {
+        assert_eq!(
+            parse_err("héllo <blue>"),
+            MarkupError::UnknownTag {
+                name: "blue".to_string(),
+                offset: 7,
+            }
+        );
+    }
+
+    #[test]
+    fn unterminated_tag_is_an_error() {
+        assert_eq!(
+            parse_err("a <red"),
+            MarkupError::UnterminatedTag { offset: 2 }
+        );
+    }
+
+    #[test]
+    fn mismatched_close_is_an_error() {
+        assert_eq!(
+            parse_err("<red><size=30>a</red></size>"),
+            MarkupError::MismatchedClose {
+                expected: "size".to_string(),
+                found: "red".to_string(),
+                offset: 15,
+            }
+        );
+    }
+
+    #[test]
+    fn close_without_open_tag_is_an_error() {
+        assert_eq!(
+            parse_err("a</red>"),
+            MarkupError::UnexpectedClose {
+                name: "red".to_string(),
+                offset: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn unclosed_tag_is_reported_at_its_offset() {
+        assert_eq!(
+            parse_err("a <red>b <size=30>c</size>"),
+            MarkupError::UnclosedTag {
+                name: "red".to_string(),
+                offset: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn trailing_escape_is_an_error() {
+        assert_eq!(parse_err(r"a \"), MarkupError::TrailingEscape { offset: 2 });
+    }
+
+    #[test]
+    fn sizes_must_be_positive_numbers() {
+        for value in ["0", "-4", "big", "inf", "NaN", ""] {
+            assert_eq!(
+                parse_err(&format!("<size={value}>a</size>")),
+                MarkupError::InvalidSize {
+                    value: value.to_string(),
+                    offset: 0,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_font_is_an_error() {
+        assert_eq!(
+            parse_err("ab<font=serif>c</font>"),
+            MarkupError::UnknownFont {
+                name: "serif".to_string(),
+                offset: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn errors_display_their_offsets() {
+        assert_eq!(
+            parse_err("a</red>").to_string(),
+            "closing tag `</red>` at byte 1 has no open tag"
+        );
+    }
+}
@@ -0,0 +1,354 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use bevy::render::{Extract, RenderApp};
+use bevy::sprite::Anchor;
+use bevy::text::{PositionedGlyph, TextLayoutInfo};
+use bevy::ui::{ExtractedUiMaterialNode, ExtractedUiMaterialNodes, RenderUiSystem};
+use bevy::utils::{HashMap, HashSet};
+
+use crate::{
+    drawn_global_transform, is_valid_scale_factor, text_transform, ui_text_stack_index,
+    RelativeDepth, ScreenOffset, TextSnapping, UiText, UiTextKeepUpright, UiTextLayoutScale,
+    UiTextStackIndexFn,
+};
+
+/// A [`UiMaterial`] that can draw the glyphs of a [`UiText`].
+///
+/// Each glyph is drawn as its own material node, by a copy of the text's material that
+/// [`set_glyph`](Self::set_glyph) was called on with the glyph's atlas texture, its rect in the
+/// atlas in UVs and its section's color. The material's fragment shader maps the `uv` of bevy_ui's
+/// `UiVertexOutput`, which goes from 0 to 1 over the glyph, into the `uv_rect` to sample the atlas.
+///
+/// bevy_ui's material pipeline only passes a node's rect and its material to the shader, so the
+/// glyph is passed in the material. A copy is made the first time a glyph is drawn in a color
+/// with a material, and shared by every text drawn with it, so a typewriter revealing dialogue
+/// only makes copies for the characters it hasn't drawn yet. Modifying the text's material,
+/// e.g. animating its uniforms, clones it into each of its copies on the next frame, once its
+/// [`AssetEvent`] is sent. bevy_ui batches nodes by material, so the glyphs are drawn with about
+/// a draw call each. Copies are kept until the text's material is removed.
+///
+/// Everything applied per glyph when the default glyph nodes are extracted doesn't apply to a
+/// material text: [`UiTextBlink`](crate::UiTextBlink), group opacity, [`TextTint`](crate::TextTint)
+/// and the other color overrides, shadows, outlines, decorations,
+/// [`MinPixelSize`](crate::MinPixelSize), [`HorizontalScroll`](crate::HorizontalScroll) and
+/// viewport culling. Fade or tint the text in the material instead.
+pub trait UiTextGlyphMaterial: UiMaterial {
+    fn set_glyph(&mut self, glyph: &UiTextMaterialGlyph);
+}
+
+/// The glyph drawn by a copy of a [`UiTextGlyphMaterial`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct UiTextMaterialGlyph {
+    /// The font atlas the glyph is in.
+    pub texture: Handle<Image>,
+    /// The glyph's rect in the atlas, in UVs from 0 to 1.
+    pub uv_rect: Rect,
+    /// The color of the glyph's section.
+    pub color: LinearRgba,
+}
+
+/// Draws the text with a custom [`UiMaterial`] instead of the default glyph nodes.
+///
+/// Requires the `material` feature and a [`UiTextMaterialPlugin`] for `M`.
+#[derive(Component, Clone, Debug, Deref, DerefMut)]
+pub struct UiTextMaterial<M: UiTextGlyphMaterial>(pub Handle<M>);
+
+/// Marks texts drawn by a [`UiTextMaterial`], so they aren't also drawn as glyph nodes.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct UsesUiTextMaterial;
+
+/// Adds support for drawing texts with [`UiTextMaterial<M>`].
+///
+/// Adds bevy_ui's [`UiMaterialPlugin<M>`] if it hasn't been added already.
+pub struct UiTextMaterialPlugin<M: UiTextGlyphMaterial>(PhantomData<M>);
+
+impl<M: UiTextGlyphMaterial> Default for UiTextMaterialPlugin<M> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<M: UiTextGlyphMaterial> Plugin for UiTextMaterialPlugin<M>
+where
+    M::Data: PartialEq + Eq + std::hash::Hash + Clone,
+{
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<UiMaterialPlugin<M>>() {
+            app.add_plugins(UiMaterialPlugin::<M>::default());
+        }
+        app.init_resource::<UiTextGlyphMaterials<M>>().add_systems(
+            PostUpdate,
+            (
+                mark_ui_text_materials::<M>,
+                update_ui_text_glyph_materials::<M>.after(crate::update_ui_independent_text_layout),
+            ),
+        );
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+        render_app.add_systems(
+            ExtractSchedule,
+            extract_ui_text_material_nodes::<M>.after(RenderUiSystem::ExtractText),
+        );
+    }
+}
+
+pub fn mark_ui_text_materials<M: UiTextGlyphMaterial>(
+    mut commands: Commands,
+    added_query: Query<Entity, Added<UiTextMaterial<M>>>,
+    mut removed: RemovedComponents<UiTextMaterial<M>>,
+) {
+    for entity in &added_query {
+        commands.entity(entity).insert(UsesUiTextMaterial);
+    }
+    for entity in removed.read() {
+        if let Some(mut entity_commands) = commands.get_entity(entity) {
+            entity_commands.remove::<UsesUiTextMaterial>();
+        }
+    }
+}
+
+/// The copies of each [`UiTextMaterial`] drawing a glyph, by the text's material, the glyph's atlas
+/// texture and index in it, and its color.
+#[derive(Resource)]
+pub struct UiTextGlyphMaterials<M: UiTextGlyphMaterial> {
+    materials: HashMap<GlyphMaterialKey<M>, (Handle<M>, UiTextMaterialGlyph)>,
+}
+
+type GlyphMaterialKey<M> = (AssetId<M>, AssetId<Image>, usize, [u32; 4]);
+
+impl<M: UiTextGlyphMaterial> Default for UiTextGlyphMaterials<M> {
+    fn default() -> Self {
+        Self {
+            materials: HashMap::default(),
+        }
+    }
+}
+
+impl<M: UiTextGlyphMaterial> UiTextGlyphMaterials<M> {
+    /// The copy of `material` drawing `glyph` in `color`.
+    pub fn get(
+        &self,
+        material: AssetId<M>,
+        glyph: &PositionedGlyph,
+        color: LinearRgba,
+    ) -> Option<AssetId<M>> {
+        self.materials
+            .get(&glyph_material_key(material, glyph, color))
+            .map(|(handle, _)| handle.id())
+    }
+
+    /// Number of glyph materials.
+    pub fn len(&self) -> usize {
+        self.materials.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.materials.is_empty()
+    }
+}
+
+fn glyph_material_key<M: UiTextGlyphMaterial>(
+    material: AssetId<M>,
+    glyph: &PositionedGlyph,
+    color: LinearRgba,
+) -> GlyphMaterialKey<M> {
+    (
+        material,
+        glyph.atlas_info.texture.id(),
+        glyph.atlas_info.glyph_index,
+        color.to_f32_array().map(f32::to_bits),
+    )
+}
+
+/// The color of a glyph drawn with a material, that of its section.
+fn glyph_color(text: &UiText, glyph: &PositionedGlyph) -> LinearRgba {
+    text.sections
+        .get(glyph.section_index)
+        .map_or(LinearRgba::WHITE, |section| section.style.color.into())
+}
+
+/// Makes a copy of the text's material for each new glyph of texts with a changed layout,
+/// colors or material, and updates the copies of modified materials.
+#[allow(clippy::type_complexity)]
+pub fn update_ui_text_glyph_materials<M: UiTextGlyphMaterial>(
+    mut glyph_materials: ResMut<UiTextGlyphMaterials<M>>,
+    mut materials: ResMut<Assets<M>>,
+    mut material_events: EventReader<AssetEvent<M>>,
+    texture_atlases: Res<Assets<TextureAtlasLayout>>,
+    text_query: Query<
+        (&UiText, &TextLayoutInfo, &UiTextMaterial<M>),
+        Or<(
+            Changed<UiText>,
+            Changed<TextLayoutInfo>,
+            Changed<UiTextMaterial<M>>,
+        )>,
+    >,
+) {
+    let mut modified = HashSet::new();
+    for event in material_events.read() {
+        match event {
+            AssetEvent::Modified { id } => {
+                modified.insert(*id);
+            }
+            AssetEvent::Removed { id } => {
+                glyph_materials
+                    .materials
+                    .retain(|(material, ..), _| material != id);
+            }
+            _ => {}
+        }
+    }
+    // the copies themselves are modified here, and are never the material of a text
+    for ((material, ..), (handle, glyph)) in &glyph_materials.materials {
+        if !modified.contains(material) {
+            continue;
+        }
+        let Some(mut copy) = materials.get(*material).cloned() else {
+            continue;
+        };
+        copy.set_glyph(glyph);
+        materials.insert(handle, copy);
+    }
+
+    for (text, text_layout, material) in &text_query {
+        let Some(base) = materials.get(&material.0).cloned() else {
+            continue;
+        };
+        for glyph in &text_layout.glyphs {
+            let color = glyph_color(text, glyph);
+            let key = glyph_material_key(material.id(), glyph, color);
+            if glyph_materials.materials.contains_key(&key) {
+                continue;
+            }
+            let Some(atlas) = texture_atlases.get(&glyph.atlas_info.texture_atlas) else {
+                continue;
+            };
+            let rect = atlas.textures[glyph.atlas_info.glyph_index].as_rect();
+            let atlas_size = atlas.size.as_vec2();
+            let glyph = UiTextMaterialGlyph {
+                texture: glyph.atlas_info.texture.clone(),
+                uv_rect: Rect::from_corners(rect.min / atlas_size, rect.max / atlas_size),
+                color,
+            };
+            let mut copy = base.clone();
+            copy.set_glyph(&glyph);
+            glyph_materials
+                .materials
+                .insert(key, (materials.add(copy), glyph));
+        }
+    }
+}
+
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn extract_ui_text_material_nodes<M: UiTextGlyphMaterial>(
+    mut extracted_uinodes: ResMut<ExtractedUiMaterialNodes<M>>,
+    mut commands: Commands,
+    default_ui_camera: Extract<DefaultUiCamera>,
+    camera_query: Extract<Query<&Camera>>,
+    node_query: Extract<Query<&Node>>,
+    stack_index_fn: Extract<Res<UiTextStackIndexFn>>,
+    glyph_materials: Extract<Res<UiTextGlyphMaterials<M>>>,
+    texture_atlases: Extract<Res<Assets<TextureAtlasLayout>>>,
+    text_query: Extract<
+        Query<(
+            Ref<GlobalTransform>,
+            &ViewVisibility,
+            &UiText,
+            &TextLayoutInfo,
+            &UiTextMaterial<M>,
+            Option<&TargetCamera>,
+            Option<&ScreenOffset>,
+            Option<&UiTextLayoutScale>,
+            Option<&Anchor>,
+            Option<(&UiTextKeepUpright, &Transform)>,
+            Option<&TextSnapping>,
+            Option<&RelativeDepth>,
+        )>,
+    >,
+) {
+    for (
+        global_transform,
+        view_visibility,
+        text,
+        text_layout,
+        material,
+        maybe_camera,
+        maybe_screen_offset,
+        maybe_layout_scale,
+        maybe_anchor,
+        maybe_keep_upright,
+        maybe_snapping,
+        maybe_relative_depth,
+    ) in &text_query
+    {
+        if !view_visibility.get() || text_layout.glyphs.is_empty() {
+            continue;
+        }
+        let Some(camera_entity) = maybe_camera
+            .map(TargetCamera::entity)
+            .or(default_ui_camera.get())
+        else {
+            continue;
+        };
+        let scale_factor = camera_query
+            .get(camera_entity)
+            .ok()
+            .and_then(Camera::target_scaling_factor)
+            .unwrap_or(1.);
+        if !is_valid_scale_factor(scale_factor) {
+            continue;
+        }
+        let layout_scale_factor = maybe_layout_scale.map_or(scale_factor, UiTextLayoutScale::get);
+        let inverse_scale_factor = layout_scale_factor.recip();
+        let size = text_layout.logical_size * inverse_scale_factor;
+        let transform = text_transform(
+            &drawn_global_transform(&global_transform, maybe_keep_upright),
+            size,
+            maybe_anchor,
+            scale_factor,
+            maybe_screen_offset.map_or(Vec2::ZERO, |offset| offset.0),
+            maybe_snapping
+                .copied()
+                .unwrap_or_default()
+                .snaps(global_transform.is_changed()),
+        );
+        let stack_index = ui_text_stack_index(
+            &stack_index_fn,
+            &global_transform,
+            maybe_relative_depth,
+            &node_query,
+        ) as usize;
+        for glyph in &text_layout.glyphs {
+            // made by `update_ui_text_glyph_materials` once the glyph's atlas exists
+            let Some(glyph_material) =
+                glyph_materials.get(material.id(), glyph, glyph_color(text, glyph))
+            else {
+                continue;
+            };
+            let Some(atlas) = texture_atlases.get(&glyph.atlas_info.texture_atlas) else {
+                continue;
+            };
+            let glyph_size = atlas.textures[glyph.atlas_info.glyph_index]
+                .size()
+                .as_vec2()
+                * inverse_scale_factor;
+            extracted_uinodes.uinodes.insert(
+                commands.spawn_empty().id(),
+                ExtractedUiMaterialNode {
+                    stack_index,
+                    transform: transform
+                        * Mat4::from_translation(
+                            (glyph.position * inverse_scale_factor).extend(0.),
+                        ),
+                    rect: Rect::from_corners(Vec2::ZERO, glyph_size),
+                    border: [0.; 4],
+                    material: glyph_material,
+                    clip: None,
+                    camera_entity,
+                },
+            );
+        }
+    }
+}
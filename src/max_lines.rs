@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+use bevy::text::TextLayoutInfo;
+
+use crate::lines::{glyph_lines, line_baseline};
+
+/// Limits a [`UiText`](crate::UiText) to its first lines, the glyphs of any lines past the limit
+/// are dropped after layout.
+///
+/// The layout's height shrinks to the kept lines, its width stays that of all the lines so
+/// justified lines keep their positions. No ellipsis is added to the last kept line. Combine with
+/// [`UiTextBounds`](crate::UiTextBounds) to clip a paragraph to a fixed number of wrapped lines.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaxLines(pub usize);
+
+impl Default for MaxLines {
+    fn default() -> Self {
+        Self(1)
+    }
+}
+
+/// Drops the glyphs of the lines past `max_lines`.
+///
+/// Applied while the glyphs are still in the order they were laid out in.
+pub(crate) fn truncate_lines(layout: &mut TextLayoutInfo, max_lines: MaxLines) {
+    let lines = glyph_lines(&layout.glyphs);
+    let Some(dropped) = lines.get(max_lines.0) else {
+        return;
+    };
+    let height = match lines.first() {
+        // the top of the first dropped line is as far above its baseline as the top of the
+        // text is above the first baseline, which also counts empty lines without glyphs
+        Some(first) if 0 < max_lines.0 => {
+            line_baseline(&layout.glyphs[dropped.clone()])
+                - line_baseline(&layout.glyphs[first.clone()])
+        }
+        _ => 0.,
+    };
+    layout.logical_size.y = height.clamp(0., layout.logical_size.y);
+    layout.glyphs.truncate(dropped.start);
+}
@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+
+use crate::UiText;
+
+/// Multiplies the opacity of every [`UiText`] on this entity and its descendants, e.g. to fade
+/// out a whole menu from its root entity.
+///
+/// Nested groups multiply together, and with the alpha of each text's
+/// [`TextTint`](crate::TextTint). Only texts are faded, other UI nodes and sprites in the
+/// hierarchy aren't. Use a [`UiTextAlpha`] to fade a single text.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextGroupOpacity(pub f32);
+
+impl Default for UiTextGroupOpacity {
+    fn default() -> Self {
+        Self(1.)
+    }
+}
+
+/// Multiplies the alpha of everything drawn for a [`UiText`], e.g. to fade it in or out.
+///
+/// The section colors aren't touched, so changing the alpha doesn't relayout the text. It's
+/// multiplied with the text's [`InheritedUiTextOpacity`] and the alpha of its
+/// [`TextTint`](crate::TextTint) when the glyphs are extracted.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextAlpha(pub f32);
+
+impl Default for UiTextAlpha {
+    fn default() -> Self {
+        Self(1.)
+    }
+}
+
+/// The product of the [`UiTextGroupOpacity`] of a [`UiText`] and all its ancestors.
+///
+/// Inserted and updated by [`propagate_ui_text_group_opacity`]. Texts without the component
+/// are fully opaque.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct InheritedUiTextOpacity(pub(crate) f32);
+
+impl InheritedUiTextOpacity {
+    pub fn get(&self) -> f32 {
+        self.0
+    }
+}
+
+impl Default for InheritedUiTextOpacity {
+    fn default() -> Self {
+        Self(1.)
+    }
+}
+
+/// Recomputes the [`InheritedUiTextOpacity`] of every text, but only on frames where a group
+/// opacity, the hierarchy or the set of texts changed.
+#[allow(clippy::type_complexity)]
+pub fn propagate_ui_text_group_opacity(
+    mut commands: Commands,
+    changed_query: Query<(), Or<(Changed<UiTextGroupOpacity>, Changed<Parent>, Added<UiText>)>>,
+    mut removed_opacity: RemovedComponents<UiTextGroupOpacity>,
+    mut removed_parent: RemovedComponents<Parent>,
+    group_query: Query<(Option<&UiTextGroupOpacity>, Option<&Parent>)>,
+    mut text_query: Query<(Entity, Option<&mut InheritedUiTextOpacity>), With<UiText>>,
+) {
+    let removed = removed_opacity.read().count() + removed_parent.read().count();
+    if changed_query.is_empty() && removed == 0 {
+        return;
+    }
+
+    for (entity, maybe_inherited) in &mut text_query {
+        let mut opacity = 1.;
+        let mut current = Some(entity);
+        while let Some((maybe_group, maybe_parent)) =
+            current.and_then(|current| group_query.get(current).ok())
+        {
+            if let Some(group) = maybe_group {
+                opacity *= group.0;
+            }
+            current = maybe_parent.map(Parent::get);
+        }
+
+        match maybe_inherited {
+            Some(mut inherited) => {
+                inherited.set_if_neq(InheritedUiTextOpacity(opacity));
+            }
+            None if opacity != 1. => {
+                commands
+                    .entity(entity)
+                    .insert(InheritedUiTextOpacity(opacity));
+            }
+            None => {}
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+use bevy::text::TextLayoutInfo;
+
+/// Direction in which the lines of a [`UiText`](crate::UiText) flow.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UiTextOrientation {
+    /// Lines flow left to right and are stacked downwards.
+    #[default]
+    Horizontal,
+    /// Columns flow top to bottom and are stacked leftwards, as in vertical CJK text.
+    ///
+    /// The height of the text's bounds limits the length of each column and the width of the
+    /// bounds the total width of the columns. Ideographs, kana, hangul and fullwidth forms are
+    /// drawn upright, every other glyph is rotated 90° clockwise.
+    Vertical,
+}
+
+/// Turns a text laid out in horizontal lines into vertical columns.
+///
+/// Each line becomes a column, the first on the right. This rotates the layout 90° clockwise,
+/// the glyphs themselves are only rotated when they're extracted.
+pub(crate) fn rotate_layout_to_vertical(layout: &mut TextLayoutInfo, sections: &[TextSection]) {
+    let height = layout.logical_size.y;
+    for glyph in &mut layout.glyphs {
+        glyph.position = Vec2::new(height - glyph.position.y, glyph.position.x);
+        if vertical_glyph_rotation(sections, glyph.section_index, glyph.byte_index).is_some() {
+            glyph.size = glyph.size.yx();
+        }
+    }
+    layout.logical_size = layout.logical_size.yx();
+}
+
+/// Rotation around its center applied to a glyph of a vertical text, if it isn't upright.
+pub(crate) fn vertical_glyph_rotation(
+    sections: &[TextSection],
+    section_index: usize,
+    byte_index: usize,
+) -> Option<Mat4> {
+    let c = sections
+        .get(section_index)
+        .and_then(|section| section.value.get(byte_index..))
+        .and_then(|value| value.chars().next())?;
+    (!is_upright_in_vertical_text(c)).then(|| Mat4::from_rotation_z(FRAC_PI_2))
+}
+
+fn is_upright_in_vertical_text(c: char) -> bool {
+    matches!(
+        c,
+        '\u{1100}'..='\u{11FF}'
+            | '\u{3000}'..='\u{303F}'
+            | '\u{3040}'..='\u{30FF}'
+            | '\u{3100}'..='\u{312F}'
+            | '\u{3130}'..='\u{318F}'
+            | '\u{31F0}'..='\u{31FF}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{AC00}'..='\u{D7AF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{FF00}'..='\u{FFEF}'
+            | '\u{1F300}'..='\u{1FAFF}'
+            | '\u{20000}'..='\u{2FFFF}'
+    )
+}
@@ -0,0 +1,45 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+/// Number of copies of each glyph drawn around it for a [`UiTextOutline`].
+pub const OUTLINE_DIRECTIONS: usize = 8;
+
+/// Draws a colored outline around the glyphs of a [`UiText`](crate::UiText), e.g. to keep HUD
+/// nameplates legible over any background.
+///
+/// The outline is drawn as copies of every glyph in `color`, moved `thickness` logical pixels
+/// in [`OUTLINE_DIRECTIONS`] directions on screen, behind the glyphs and in front of a
+/// [`UiTextShadow`](crate::UiTextShadow). That's cheap but gaps show between the copies for
+/// outlines thicker than a few pixels. Its alpha is multiplied by the alpha of each glyph.
+/// Baked texts aren't outlined.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextOutline {
+    pub color: Color,
+    pub thickness: f32,
+}
+
+impl Default for UiTextOutline {
+    fn default() -> Self {
+        Self {
+            color: Color::BLACK,
+            thickness: 1.,
+        }
+    }
+}
+
+impl UiTextOutline {
+    pub fn new(color: Color, thickness: f32) -> Self {
+        Self { color, thickness }
+    }
+
+    /// The screen space offsets, in logical pixels, the glyph copies are drawn at.
+    pub fn offsets(&self) -> impl Iterator<Item = Vec2> {
+        let thickness = self.thickness;
+        (0..OUTLINE_DIRECTIONS).map(move |direction| {
+            thickness * Vec2::from_angle(direction as f32 * TAU / OUTLINE_DIRECTIONS as f32)
+        })
+    }
+}
@@ -0,0 +1,253 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::text::BreakLineOn;
+
+use crate::{IndependentTextBundle, UiText, UiTextTime};
+
+/// Recycles short lived texts, like damage numbers and pickup notifications, instead of
+/// spawning and despawning an entity for each one.
+///
+/// Opt in by inserting the resource, then spawn texts with [`UiTextPool::acquire`]. When the
+/// [`UiTextPooled`] lifetime of an acquired text ends it's hidden and kept for the next
+/// [`acquire`](UiTextPool::acquire). A kept text whose string, font and font size match the next
+/// acquired text is preferred, as only its color is updated in place. With a
+/// [`UiTextLayoutCache`](crate::UiTextLayoutCache) that text isn't laid out again.
+///
+/// At most `max_size` hidden texts are kept, any more are despawned. Pooled entities despawned by
+/// user code are dropped from the pool.
+#[derive(Resource, Debug)]
+pub struct UiTextPool {
+    /// Maximum number of released texts kept for reuse.
+    pub max_size: usize,
+    /// Lifetime given to acquired texts.
+    pub lifetime: Duration,
+    free: Vec<PooledUiText>,
+}
+
+#[derive(Debug)]
+struct PooledUiText {
+    entity: Entity,
+    /// The text's only section, `None` for texts with several sections.
+    section: Option<TextSection>,
+    justify: JustifyText,
+    linebreak_behavior: BreakLineOn,
+}
+
+impl PooledUiText {
+    /// Whether the text is laid out like a new text of `value` in `style`, ignoring its color.
+    fn matches(&self, value: &str, style: &TextStyle) -> bool {
+        let text = Text::default();
+        self.justify == text.justify
+            && self.linebreak_behavior == text.linebreak_behavior
+            && self.section.as_ref().is_some_and(|section| {
+                section.value == value
+                    && section.style.font == style.font
+                    && section.style.font_size == style.font_size
+            })
+    }
+}
+
+impl Default for UiTextPool {
+    fn default() -> Self {
+        Self::new(256)
+    }
+}
+
+impl UiTextPool {
+    /// An empty pool keeping up to `max_size` released texts.
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            max_size,
+            lifetime: Duration::from_secs(1),
+            free: Vec::new(),
+        }
+    }
+
+    /// Number of released texts waiting to be reused.
+    pub fn len(&self) -> usize {
+        self.free.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.free.is_empty()
+    }
+
+    /// Shows a single section text at `translation` for the pool's `lifetime`, reusing a released
+    /// text if there is one.
+    ///
+    /// The returned entity may be a recycled one, so any components inserted on it by the caller
+    /// outlive the text's lifetime.
+    pub fn acquire(
+        &mut self,
+        commands: &mut Commands,
+        value: impl Into<String>,
+        style: TextStyle,
+        translation: Vec3,
+    ) -> Entity {
+        let value = value.into();
+        self.free
+            .retain(|pooled| commands.get_entity(pooled.entity).is_some());
+        let pooled = self
+            .free
+            .iter()
+            .rposition(|pooled| pooled.matches(&value, &style))
+            .or_else(|| self.free.len().checked_sub(1))
+            .map(|index| self.free.swap_remove(index));
+        let pooled_component = UiTextPooled::new(self.lifetime);
+        let transform = Transform::from_translation(translation);
+
+        match pooled {
+            Some(pooled) => {
+                let mut entity_commands = commands.entity(pooled.entity);
+                // `try_insert`, as the entity may still be despawned before the commands are applied
+                entity_commands.try_insert((pooled_component, transform, Visibility::Inherited));
+                if pooled.matches(&value, &style) {
+                    let color = style.color;
+                    entity_commands.add(move |entity: Entity, world: &mut World| {
+                        if let Some(mut text) = world.get_mut::<UiText>(entity) {
+                            UiText::set_section_color(&mut text, 0, color);
+                        }
+                    });
+                } else {
+                    entity_commands.try_insert(UiText::from_section(value, style));
+                }
+                pooled.entity
+            }
+            None => commands
+                .spawn((
+                    IndependentTextBundle {
+                        text: UiText::from_section(value, style),
+                        transform,
+                        ..Default::default()
+                    },
+                    pooled_component,
+                ))
+                .id(),
+        }
+    }
+}
+
+/// The remaining lifetime of a text acquired from a [`UiTextPool`].
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextPooled {
+    pub lifetime: Duration,
+    age: Duration,
+    released: bool,
+}
+
+impl Default for UiTextPooled {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(1))
+    }
+}
+
+impl UiTextPooled {
+    pub fn new(lifetime: Duration) -> Self {
+        Self {
+            lifetime,
+            age: Duration::ZERO,
+            released: false,
+        }
+    }
+
+    /// Whether the text has been returned to its pool.
+    pub fn is_released(&self) -> bool {
+        self.released
+    }
+}
+
+pub fn release_pooled_ui_texts(
+    mut commands: Commands,
+    time: UiTextTime,
+    mut pool: ResMut<UiTextPool>,
+    mut removed_pooled: RemovedComponents<UiTextPooled>,
+    mut text_query: Query<(Entity, &mut UiTextPooled, &mut Visibility, &UiText)>,
+) {
+    let removed: Vec<Entity> = removed_pooled.read().collect();
+    if !removed.is_empty() {
+        pool.free.retain(|pooled| !removed.contains(&pooled.entity));
+    }
+
+    let delta = time.delta();
+    for (entity, mut pooled, mut visibility, text) in &mut text_query {
+        if pooled.released {
+            continue;
+        }
+        pooled.age += delta;
+        if pooled.age < pooled.lifetime {
+            continue;
+        }
+        if pool.max_size <= pool.free.len() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        pooled.released = true;
+        *visibility = Visibility::Hidden;
+        let section = match text.sections.as_slice() {
+            [section] => Some(section.clone()),
+            _ => None,
+        };
+        pool.free.push(PooledUiText {
+            entity,
+            section,
+            justify: text.justify,
+            linebreak_behavior: text.linebreak_behavior,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pooled(value: &str, style: TextStyle) -> PooledUiText {
+        PooledUiText {
+            entity: Entity::PLACEHOLDER,
+            section: Some(TextSection::new(value, style)),
+            justify: JustifyText::Left,
+            linebreak_behavior: BreakLineOn::WordBoundary,
+        }
+    }
+
+    fn style(font_size: f32, color: Color) -> TextStyle {
+        TextStyle {
+            font_size,
+            color,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn texts_differing_only_in_color_match() {
+        let pooled = pooled("12", style(20., Color::WHITE));
+        assert!(pooled.matches("12", &style(20., Color::BLACK)));
+        assert!(!pooled.matches("13", &style(20., Color::WHITE)));
+        assert!(!pooled.matches("12", &style(24., Color::WHITE)));
+    }
+
+    #[test]
+    fn texts_relaid_out_differently_dont_match() {
+        let centered = PooledUiText {
+            justify: JustifyText::Center,
+            ..pooled("12", style(20., Color::WHITE))
+        };
+        assert!(!centered.matches("12", &style(20., Color::WHITE)));
+        let unwrapped = PooledUiText {
+            linebreak_behavior: BreakLineOn::NoWrap,
+            ..pooled("12", style(20., Color::WHITE))
+        };
+        assert!(!unwrapped.matches("12", &style(20., Color::WHITE)));
+    }
+
+    #[test]
+    fn texts_with_several_sections_dont_match() {
+        let pooled = PooledUiText {
+            section: None,
+            ..pooled("12", style(20., Color::WHITE))
+        };
+        assert!(!pooled.matches("12", &style(20., Color::WHITE)));
+    }
+}
@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::{is_valid_scale_factor, UiTextLayoutPipeline};
+
+/// Characters of a font and size to add to the font atlases ahead of time.
+#[derive(Clone, Debug)]
+pub struct GlyphPreload {
+    pub font: Handle<Font>,
+    /// In logical pixels, like [`TextStyle::font_size`].
+    pub font_size: f32,
+    pub chars: String,
+    /// The scale factor the glyphs were last preloaded at.
+    preloaded_at: Option<f32>,
+}
+
+impl GlyphPreload {
+    pub fn new(font: Handle<Font>, font_size: f32, chars: impl Into<String>) -> Self {
+        Self {
+            font,
+            font_size,
+            chars: chars.into(),
+            preloaded_at: None,
+        }
+    }
+
+    /// Whether the glyphs have been added to the atlases.
+    pub fn is_preloaded(&self) -> bool {
+        self.preloaded_at.is_some()
+    }
+}
+
+/// Glyphs preloaded with [`UiTextLayoutPipeline::preload_glyphs`] at the primary window's scale
+/// factor, for games that know their character set up front.
+///
+/// Insert it from a startup system, each font's glyphs are added to the atlases once it has
+/// loaded, and again whenever the scale factor changes:
+///
+/// ```ignore
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     let font = asset_server.load("Topaz-8.ttf");
+///     commands.insert_resource(UiTextGlyphPreloads(vec![GlyphPreload::new(
+///         font,
+///         32.,
+///         "0123456789+-",
+///     )]));
+/// }
+/// ```
+///
+/// Preloading still rasterizes the glyphs in a single frame, but while e.g. a loading screen is
+/// shown rather than when the first text using them appears.
+#[derive(Resource, Clone, Debug, Default, Deref, DerefMut)]
+pub struct UiTextGlyphPreloads(pub Vec<GlyphPreload>);
+
+pub fn preload_ui_text_glyphs(
+    mut preloads: ResMut<UiTextGlyphPreloads>,
+    mut pipeline: UiTextLayoutPipeline,
+    windows: Query<&Window, With<PrimaryWindow>>,
+) {
+    let scale_factor = match windows.get_single() {
+        Ok(window) if is_valid_scale_factor(window.scale_factor()) => window.scale_factor(),
+        _ => return,
+    };
+    // checked first so the resource is only changed when there's something to preload
+    if preloads
+        .iter()
+        .all(|preload| preload.preloaded_at == Some(scale_factor))
+    {
+        return;
+    }
+    for preload in preloads.iter_mut() {
+        if preload.preloaded_at == Some(scale_factor) {
+            continue;
+        }
+        // retried next frame while the font is loading
+        if pipeline
+            .preload_glyphs(
+                &preload.font,
+                preload.font_size,
+                &preload.chars,
+                scale_factor,
+            )
+            .is_ok()
+        {
+            preload.preloaded_at = Some(scale_factor);
+        }
+    }
+}
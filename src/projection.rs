@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+/// Labels are stacked by the integer part of their z translation,
+/// so depths in `0..=1` are scaled up to keep nearer labels on top.
+const DEPTH_STACK_RANGE: f32 = 1000.;
+
+/// Projects a world position into a translation for a [`UiText`](crate::UiText)'s [`Transform`].
+///
+/// `x` and `y` are the logical position within the camera's viewport. `z` increases the nearer
+/// the point is to the camera, from `0` at the far plane to `1000` at the near plane, so nearer
+/// labels are drawn on top of further ones.
+///
+/// Returns `None` if the point is outside of the camera's view frustum depth range.
+pub fn world_to_ui_text_position(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    world_position: Vec3,
+) -> Option<Vec3> {
+    let viewport_position = camera.world_to_viewport(camera_transform, world_position)?;
+    // bevy uses reverse-z, depth is 1 at the near plane and 0 at the far plane
+    let depth = camera.world_to_ndc(camera_transform, world_position)?.z;
+    Some(viewport_position.extend(depth.clamp(0., 1.) * DEPTH_STACK_RANGE))
+}
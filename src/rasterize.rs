@@ -0,0 +1,144 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::text::{
+    FontAtlasSets, PositionedGlyph, TextLayoutInfo, TextPipeline, TextSettings, YAxisOrientation,
+};
+
+use crate::{justify_within_logical_box, normalize_bounds, UiTextLayoutSettings};
+
+/// A [`Text`] baked into an [`Image`] by [`UiTextRasterizer`].
+#[derive(Clone, Debug)]
+pub struct RasterizedText {
+    pub image: Handle<Image>,
+    /// Size of the image in physical pixels.
+    pub size: UVec2,
+}
+
+/// Lays out text and composites its glyphs into an owned [`Image`],
+/// for use as a texture on meshes, sprites, materials etc.
+#[derive(SystemParam)]
+pub struct UiTextRasterizer<'w> {
+    fonts: Res<'w, Assets<Font>>,
+    images: ResMut<'w, Assets<Image>>,
+    texture_atlases: ResMut<'w, Assets<TextureAtlasLayout>>,
+    font_atlas_sets: ResMut<'w, FontAtlasSets>,
+    text_pipeline: ResMut<'w, TextPipeline>,
+    text_settings: Res<'w, TextSettings>,
+    layout_settings: Option<Res<'w, UiTextLayoutSettings>>,
+}
+
+impl UiTextRasterizer<'_> {
+    /// Lays out `text` and rasterizes it into a new image.
+    ///
+    /// `bounds` is in logical pixels, the image is sized in physical pixels.
+    /// Returns [`TextError::NoSuchFont`] until the fonts have finished loading.
+    pub fn rasterize(
+        &mut self,
+        text: &Text,
+        bounds: Option<Vec2>,
+        scale_factor: f32,
+    ) -> Result<RasterizedText, TextError> {
+        let bounds = bounds.map_or(Vec2::INFINITY, |bounds| {
+            normalize_bounds(bounds) * scale_factor
+        });
+        let text_settings = self
+            .layout_settings
+            .as_ref()
+            .and_then(|settings| settings.text_settings.as_ref())
+            .unwrap_or(&self.text_settings);
+        let mut layout = self.text_pipeline.queue_text(
+            &self.fonts,
+            &text.sections,
+            scale_factor,
+            text.justify,
+            text.linebreak_behavior,
+            bounds,
+            &mut self.font_atlas_sets,
+            &mut self.texture_atlases,
+            &mut self.images,
+            text_settings,
+            YAxisOrientation::TopToBottom,
+        )?;
+        justify_within_logical_box(&mut layout, text.justify, bounds.x);
+        let image = rasterize_text_layout(text, &layout, &self.texture_atlases, &self.images);
+        let size = image.size();
+        Ok(RasterizedText {
+            image: self.images.add(image),
+            size,
+        })
+    }
+}
+
+/// Composites the glyphs of an already computed layout into a new [`Image`].
+///
+/// The glyph atlases must still be available on the CPU, which is the default for font atlases.
+pub fn rasterize_text_layout(
+    text: &Text,
+    layout: &TextLayoutInfo,
+    texture_atlases: &Assets<TextureAtlasLayout>,
+    images: &Assets<Image>,
+) -> Image {
+    let size = layout.logical_size.ceil().as_uvec2().max(UVec2::ONE);
+    let mut data = vec![0; (size.x * size.y * 4) as usize];
+
+    for PositionedGlyph {
+        position,
+        atlas_info,
+        section_index,
+        ..
+    } in &layout.glyphs
+    {
+        let (Some(atlas), Some(atlas_image)) = (
+            texture_atlases.get(&atlas_info.texture_atlas),
+            images.get(&atlas_info.texture),
+        ) else {
+            continue;
+        };
+        let color = text.sections[*section_index].style.color.to_srgba();
+        let rect = atlas.textures[atlas_info.glyph_index];
+        let glyph_size = rect.size();
+        let top_left = (*position - 0.5 * glyph_size.as_vec2()).round().as_ivec2();
+
+        for y in 0..glyph_size.y {
+            for x in 0..glyph_size.x {
+                let target = top_left + IVec2::new(x as i32, y as i32);
+                if target.cmplt(IVec2::ZERO).any() || target.cmpge(size.as_ivec2()).any() {
+                    continue;
+                }
+                let source =
+                    (((rect.min.y + y) * atlas_image.width() + rect.min.x + x) * 4) as usize;
+                let alpha = color.alpha * atlas_image.data[source + 3] as f32 / 255.;
+                if alpha <= 0. {
+                    continue;
+                }
+                let target = ((target.y as u32 * size.x + target.x as u32) * 4) as usize;
+                blend_over(&mut data[target..target + 4], color, alpha);
+            }
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: size.x,
+            height: size.y,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+fn blend_over(pixel: &mut [u8], color: Srgba, alpha: f32) {
+    let target_alpha = pixel[3] as f32 / 255.;
+    let out_alpha = alpha + target_alpha * (1. - alpha);
+    for (channel, value) in [color.red, color.green, color.blue].into_iter().enumerate() {
+        let target = pixel[channel] as f32 / 255.;
+        let out = (value * alpha + target * target_alpha * (1. - alpha)) / out_alpha;
+        pixel[channel] = (out * 255.).round() as u8;
+    }
+    pixel[3] = (out_alpha * 255.).round() as u8;
+}
@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::UiTextBounds;
+
+/// Bounds of a [`UiText`](crate::UiText) relative to the size of its camera's viewport, e.g. to
+/// always wrap subtitles at 60% of the window width.
+///
+/// Resolved into the text's [`UiTextBounds`] every frame before layout, so the text is laid out
+/// again whenever the resolved bounds change, after the window is resized, its scale factor
+/// changes or the camera's viewport changes. `Val::Percent` is relative to the viewport's width
+/// for `width` and its height for `height`. `Val::Auto` leaves the axis unbounded.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextRelativeBounds {
+    pub width: Val,
+    pub height: Val,
+}
+
+impl Default for UiTextRelativeBounds {
+    fn default() -> Self {
+        Self {
+            width: Val::Auto,
+            height: Val::Auto,
+        }
+    }
+}
+
+impl UiTextRelativeBounds {
+    /// The bounds in logical pixels for a viewport of the given logical size.
+    pub fn resolve(&self, viewport_size: Vec2) -> Vec2 {
+        let resolve =
+            |val: Val, parent: f32| val.resolve(parent, viewport_size).unwrap_or(f32::INFINITY);
+        Vec2::new(
+            resolve(self.width, viewport_size.x),
+            resolve(self.height, viewport_size.y),
+        )
+    }
+}
+
+pub fn resolve_ui_text_relative_bounds(
+    mut commands: Commands,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    default_ui_camera: DefaultUiCamera,
+    camera_query: Query<&Camera>,
+    mut text_query: Query<(
+        Entity,
+        &UiTextRelativeBounds,
+        Option<&TargetCamera>,
+        Option<&mut UiTextBounds>,
+    )>,
+) {
+    let window_size = window_query.get_single().ok().map(Window::size);
+    for (entity, relative_bounds, maybe_target_camera, maybe_bounds) in &mut text_query {
+        let Some(viewport_size) = maybe_target_camera
+            .map(TargetCamera::entity)
+            .or(default_ui_camera.get())
+            .and_then(|camera_entity| camera_query.get(camera_entity).ok())
+            .and_then(Camera::logical_viewport_size)
+            .or(window_size)
+        else {
+            continue;
+        };
+        let bounds = UiTextBounds {
+            size: relative_bounds.resolve(viewport_size),
+        };
+        match maybe_bounds {
+            Some(mut current) => {
+                current.set_if_neq(bounds);
+            }
+            None => {
+                commands.entity(entity).insert(bounds);
+            }
+        }
+    }
+}
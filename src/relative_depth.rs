@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+
+/// Draws a [`UiText`](crate::UiText) just above or below a Bevy UI node, instead of at the depth
+/// of its z translation.
+///
+/// The text's stack index is the node's stack index from the [`UiStack`](bevy::ui::UiStack) plus
+/// one above or minus one below. UI nodes with the same stack index are ordered by entity, so a
+/// text above a panel is drawn above the panel but ties with the node after it in the stack,
+/// often the panel's first child. Texts whose node is missing are drawn at their z translation.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct RelativeDepth {
+    pub relative_to: Entity,
+    pub above: bool,
+}
+
+impl RelativeDepth {
+    pub fn above(node: Entity) -> Self {
+        Self {
+            relative_to: node,
+            above: true,
+        }
+    }
+
+    pub fn below(node: Entity) -> Self {
+        Self {
+            relative_to: node,
+            above: false,
+        }
+    }
+}
+
+/// Computes the stack index a text's nodes are extracted with, for games with their own depth
+/// conventions.
+///
+/// Called with the text's drawn transform and, for texts with a [`RelativeDepth`] whose node
+/// exists, the depth and the node. Used by the default glyph nodes, backgrounds, baked images
+/// and [`UiTextMaterial`](crate::UiTextMaterial)s. Defaults to [`default_ui_text_stack_index`].
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_ui_independent_text::UiTextStackIndexFn;
+/// // texts are drawn above every UI node
+/// App::new().insert_resource(UiTextStackIndexFn(|_, _| u32::MAX));
+/// ```
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct UiTextStackIndexFn(pub fn(&GlobalTransform, Option<(&RelativeDepth, &Node)>) -> u32);
+
+impl Default for UiTextStackIndexFn {
+    fn default() -> Self {
+        Self(default_ui_text_stack_index)
+    }
+}
+
+/// The z translation of the text, or its [`RelativeDepth`] node's stack index plus or minus one.
+pub fn default_ui_text_stack_index(
+    global_transform: &GlobalTransform,
+    relative_depth: Option<(&RelativeDepth, &Node)>,
+) -> u32 {
+    match relative_depth {
+        Some((depth, node)) if depth.above => node.stack_index().saturating_add(1),
+        Some((_, node)) => node.stack_index().saturating_sub(1),
+        None => global_transform.translation().z as u32,
+    }
+}
+
+/// The stack index the nodes of a text are extracted with.
+#[cfg(feature = "render")]
+pub(crate) fn ui_text_stack_index(
+    stack_index_fn: &UiTextStackIndexFn,
+    global_transform: &GlobalTransform,
+    relative_depth: Option<&RelativeDepth>,
+    node_query: &Query<&Node>,
+) -> u32 {
+    let relative_depth = relative_depth.and_then(|depth| {
+        node_query
+            .get(depth.relative_to)
+            .ok()
+            .map(|node| (depth, node))
+    });
+    (stack_index_fn.0)(global_transform, relative_depth)
+}
@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+use bevy::render::texture::{ImageFilterMode, ImageSampler};
+use bevy::text::TextLayoutInfo;
+use bevy::utils::HashSet;
+
+/// Samples the glyph atlases used by this text with nearest-neighbor filtering,
+/// keeping pixel fonts crisp.
+///
+/// Glyph atlases are shared through [`FontAtlasSets`](bevy::text::FontAtlasSets): there is one
+/// set of atlases per font and font size, used by every text drawn with that font and size,
+/// including bevy_ui's and `Text2d`'s. Setting this on one entity changes the sampler of those
+/// shared atlases, so every text using the same font at the same size is also drawn unfiltered.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NearestFontSampling;
+
+/// Sets the sampler of the glyph atlases used by texts with [`NearestFontSampling`].
+pub fn apply_nearest_font_sampling(
+    text_query: Query<&TextLayoutInfo, (With<NearestFontSampling>, Changed<TextLayoutInfo>)>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let atlas_images: HashSet<AssetId<Image>> = text_query
+        .iter()
+        .flat_map(|layout| &layout.glyphs)
+        .map(|glyph| glyph.atlas_info.texture.id())
+        .collect();
+    for id in atlas_images {
+        // check first, mutable access would mark the atlas modified and reupload it
+        let needs_nearest = images.get(id).is_some_and(|image| {
+            !matches!(&image.sampler, ImageSampler::Descriptor(descriptor)
+                if matches!(descriptor.mag_filter, ImageFilterMode::Nearest))
+        });
+        if needs_nearest {
+            if let Some(image) = images.get_mut(id) {
+                image.sampler = ImageSampler::nearest();
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+use bevy::text::TextLayoutInfo;
+
+use crate::UiText;
+
+/// Asset paths of the fonts of each section of the entity's [`UiText`].
+///
+/// Font handles don't survive being saved to a scene, so the paths are recorded here and
+/// the fonts are reloaded from them when the entity is spawned from a `DynamicScene`.
+/// Sections with a `None` path, or beyond the end of the list, keep their font.
+///
+/// Saving a text to a scene needs the `serde` feature, which serializes the [`UiText`] without
+/// its font handles. Deny the [`TextLayoutInfo`] when building the scene, its glyphs hold
+/// handles to the glyph atlases, a spawned text is given a new layout.
+///
+/// ```ignore
+/// let scene = DynamicSceneBuilder::from_world(world)
+///     .deny::<TextLayoutInfo>()
+///     .extract_entity(entity)
+///     .build();
+/// ```
+#[derive(Component, Clone, Debug, Default, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextFontPaths(pub Vec<Option<String>>);
+
+impl UiTextFontPaths {
+    pub fn from_text(text: &Text) -> Self {
+        Self(
+            text.sections
+                .iter()
+                .map(|section| section.style.font.path().map(ToString::to_string))
+                .collect(),
+        )
+    }
+}
+
+/// Records the asset paths of the fonts of changed texts.
+pub fn record_ui_text_font_paths(
+    mut text_query: Query<(&UiText, &mut UiTextFontPaths), Changed<UiText>>,
+) {
+    for (text, mut font_paths) in &mut text_query {
+        let mut paths = UiTextFontPaths::from_text(text);
+        // keep the paths of fonts that are still being resolved
+        for (path, old_path) in paths.iter_mut().zip(font_paths.iter()) {
+            if path.is_none() {
+                path.clone_from(old_path);
+            }
+        }
+        if *font_paths != paths {
+            *font_paths = paths;
+        }
+    }
+}
+
+/// Gives texts spawned without a [`TextLayoutInfo`], e.g. from a scene, a layout to fill.
+pub fn insert_missing_ui_text_layouts(
+    mut commands: Commands,
+    text_query: Query<Entity, (With<UiText>, Without<TextLayoutInfo>)>,
+) {
+    for entity in &text_query {
+        commands.entity(entity).insert(TextLayoutInfo::default());
+    }
+}
+
+/// Loads the fonts of texts whose [`UiTextFontPaths`] changed,
+/// e.g. after the entity was spawned from a scene.
+pub fn resolve_ui_text_font_paths(
+    asset_server: Res<AssetServer>,
+    mut text_query: Query<(&UiTextFontPaths, &mut UiText), Changed<UiTextFontPaths>>,
+) {
+    for (font_paths, mut text) in &mut text_query {
+        // checked first, mutable access would relayout the text even if no font is replaced
+        if !text
+            .sections
+            .iter()
+            .zip(font_paths.iter())
+            .any(|(section, path)| {
+                path.as_deref()
+                    .is_some_and(|path| !loads_from(section, path))
+            })
+        {
+            continue;
+        }
+        for (section, path) in text.sections.iter_mut().zip(font_paths.iter()) {
+            let Some(path) = path else {
+                continue;
+            };
+            if !loads_from(section, path) {
+                section.style.font = asset_server.load(path.clone());
+            }
+        }
+    }
+}
+
+/// Whether the section's font is loaded from `path`.
+fn loads_from(section: &TextSection, path: &str) -> bool {
+    section
+        .style
+        .font
+        .path()
+        .map(ToString::to_string)
+        .as_deref()
+        == Some(path)
+}
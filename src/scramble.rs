@@ -0,0 +1,191 @@
+use bevy::prelude::*;
+
+use crate::{UiText, UiTextTime};
+
+/// Characters a [`UiTextScramble`] substitutes for the unrevealed characters.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScrambleCharset {
+    /// `A-Z`, `a-z` and `0-9`.
+    #[default]
+    Alphanumeric,
+    /// `A-Z` and `0-9`.
+    Uppercase,
+    /// `0-9` and `A-F`.
+    Hex,
+    /// `0` and `1`.
+    Binary,
+    /// Any characters, the font needs glyphs for all of them.
+    Custom(String),
+}
+
+impl ScrambleCharset {
+    fn chars(&self) -> &str {
+        match self {
+            ScrambleCharset::Alphanumeric => {
+                "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789"
+            }
+            ScrambleCharset::Uppercase => "ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789",
+            ScrambleCharset::Hex => "0123456789ABCDEF",
+            ScrambleCharset::Binary => "01",
+            ScrambleCharset::Custom(chars) => chars,
+        }
+    }
+}
+
+/// Reveals a [`UiText`] with a decoding effect: every character is shown from the start, but
+/// the ones past the reveal front are random characters from the `charset` that lock into the
+/// real text left to right.
+///
+/// The text's section values when the scramble is first updated are the text to reveal, and the
+/// sections are rewritten with the scrambled text until it's fully revealed, when a
+/// [`UiTextScrambleFinished`] event is sent. Whitespace and newlines are never scrambled, so the
+/// lines break in the same places unless the substitutes are much wider than the real
+/// characters. Each rewrite lays the text out again, a monospace font keeps the width of the
+/// text from jittering while it's scrambled.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextScramble {
+    /// Characters locked into the real text per second.
+    pub chars_per_second: f32,
+    pub charset: ScrambleCharset,
+    /// Times per second the unrevealed characters are replaced with new random ones, zero keeps
+    /// them until they're revealed.
+    pub rescramble_rate: f32,
+    /// The section values being revealed.
+    source: Option<Vec<String>>,
+    elapsed: f32,
+    since_rescramble: f32,
+    seed: u32,
+    /// Number of revealed characters last written to the text.
+    shown: Option<usize>,
+    finished: bool,
+}
+
+impl Default for UiTextScramble {
+    fn default() -> Self {
+        Self::new(30.)
+    }
+}
+
+impl UiTextScramble {
+    pub fn new(chars_per_second: f32) -> Self {
+        Self {
+            chars_per_second,
+            charset: ScrambleCharset::default(),
+            rescramble_rate: 15.,
+            source: None,
+            elapsed: 0.,
+            since_rescramble: 0.,
+            seed: 0,
+            shown: None,
+            finished: false,
+        }
+    }
+
+    pub fn with_charset(mut self, charset: ScrambleCharset) -> Self {
+        self.charset = charset;
+        self
+    }
+
+    pub fn with_rescramble_rate(mut self, rescramble_rate: f32) -> Self {
+        self.rescramble_rate = rescramble_rate;
+        self
+    }
+
+    /// Whether the whole text has been revealed.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Scrambles the text again and restarts the reveal. Unless the scramble has finished, the
+    /// text being revealed stays the same.
+    pub fn restart(&mut self) {
+        if self.finished {
+            self.source = None;
+        }
+        self.elapsed = 0.;
+        self.since_rescramble = 0.;
+        self.shown = None;
+        self.finished = false;
+    }
+
+    /// A substitute for the character at `index`, which stays the same until the next rescramble.
+    fn substitute(&self, index: usize) -> Option<char> {
+        let chars = self.charset.chars();
+        let count = chars.chars().count();
+        if count == 0 {
+            return None;
+        }
+        let hash = hash(self.seed ^ hash(index as u32));
+        chars.chars().nth(hash as usize % count)
+    }
+}
+
+/// A cheap integer hash, good enough to pick random looking characters.
+fn hash(mut x: u32) -> u32 {
+    x = (x ^ (x >> 16)).wrapping_mul(0x7feb_352d);
+    x = (x ^ (x >> 15)).wrapping_mul(0x846c_a68b);
+    x ^ (x >> 16)
+}
+
+/// Sent when a [`UiTextScramble`] has revealed its whole text.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UiTextScrambleFinished(pub Entity);
+
+pub fn update_ui_text_scrambles(
+    time: UiTextTime,
+    mut finished_events: EventWriter<UiTextScrambleFinished>,
+    mut scramble_query: Query<(Entity, &mut UiTextScramble, &mut UiText)>,
+) {
+    for (entity, mut scramble, mut text) in &mut scramble_query {
+        if scramble.finished {
+            continue;
+        }
+        let scramble = scramble.as_mut();
+        let source = scramble
+            .source
+            .get_or_insert_with(|| text.sections.iter().map(|s| s.value.clone()).collect());
+        let len: usize = source.iter().map(|value| value.chars().count()).sum();
+
+        scramble.elapsed += time.delta_seconds();
+        let revealed = ((scramble.elapsed * scramble.chars_per_second) as usize).min(len);
+        let mut rescrambled = false;
+        if 0. < scramble.rescramble_rate {
+            scramble.since_rescramble += time.delta_seconds();
+            if 1. / scramble.rescramble_rate <= scramble.since_rescramble {
+                scramble.since_rescramble = 0.;
+                scramble.seed = hash(scramble.seed.wrapping_add(1));
+                rescrambled = true;
+            }
+        }
+        if scramble.shown == Some(revealed) && !rescrambled {
+            continue;
+        }
+        scramble.shown = Some(revealed);
+
+        let source = scramble.source.as_ref().unwrap();
+        let mut index = 0;
+        for (section, value) in source.iter().enumerate() {
+            let scrambled: String = value
+                .chars()
+                .map(|c| {
+                    let substitute = if index < revealed || c.is_whitespace() {
+                        None
+                    } else {
+                        scramble.substitute(index)
+                    };
+                    index += 1;
+                    substitute.unwrap_or(c)
+                })
+                .collect();
+            UiText::set_section_value(&mut text, section, &scrambled);
+        }
+
+        if revealed == len {
+            scramble.finished = true;
+            finished_events.send(UiTextScrambleFinished(entity));
+        }
+    }
+}
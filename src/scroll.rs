@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+
+/// Scrolls the glyphs of a [`UiText`](crate::UiText) wider than its
+/// [`UiTextBounds`](crate::UiTextBounds) left by this many logical pixels, clipping them to the
+/// width of the bounds. For single line text fields showing the end of a long line.
+///
+/// The scroll is clamped between `0` and the width the text overflows its bounds by, so
+/// `HorizontalScroll(f32::MAX)` always shows the end of the text. The text is positioned as if it
+/// were only as wide as its bounds. Texts that fit in their bounds and unbounded texts aren't
+/// scrolled. Give the text [`BreakLineOn::NoWrap`](bevy::text::BreakLineOn::NoWrap) so it isn't
+/// wrapped to the bounds instead.
+///
+/// The clip rect is axis aligned, so rotated texts are clipped to the bounding box of their
+/// scroll window.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HorizontalScroll(pub f32);
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+#[cfg(feature = "render")]
+use bevy::text::PositionedGlyph;
+
+#[cfg(feature = "render")]
+use crate::lines::{glyph_lines, glyphs_rect};
+
+/// Highlights a range of a [`UiText`](crate::UiText)'s glyphs, drawn behind the text.
+///
+/// `start..end` indexes the glyphs of the text's [`TextLayoutInfo`](bevy::text::TextLayoutInfo),
+/// whitespace has no glyphs. Selections spanning several lines are drawn as one rect per line,
+/// as tall as the line's tallest glyphs.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextSelection {
+    pub start: usize,
+    pub end: usize,
+    pub color: Color,
+}
+
+impl Default for TextSelection {
+    fn default() -> Self {
+        Self {
+            start: 0,
+            end: 0,
+            color: Color::srgba(0.2, 0.4, 1., 0.5),
+        }
+    }
+}
+
+#[cfg(feature = "render")]
+impl TextSelection {
+    /// Rects covering the selected glyphs of each line, in the layout's coordinates.
+    pub(crate) fn line_rects(&self, glyphs: &[PositionedGlyph]) -> Vec<Rect> {
+        glyph_lines(glyphs)
+            .into_iter()
+            .filter_map(|line| {
+                let start = line.start.max(self.start);
+                let end = line.end.min(self.end);
+                if end <= start {
+                    return None;
+                }
+                let line_rect = glyphs_rect(&glyphs[line]);
+                let selected_rect = glyphs_rect(&glyphs[start..end]);
+                Some(Rect::new(
+                    selected_rect.min.x,
+                    line_rect.min.y,
+                    selected_rect.max.x,
+                    line_rect.max.y,
+                ))
+            })
+            .collect()
+    }
+}
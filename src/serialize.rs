@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+use bevy::text::BreakLineOn;
+use serde::de::DeserializeSeed;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::UiText;
+
+#[derive(Serialize, Deserialize)]
+struct SerializedSection {
+    value: String,
+    /// Asset path of the font, `None` for the default font.
+    font: Option<String>,
+    font_size: f32,
+    color: Color,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializedText {
+    sections: Vec<SerializedSection>,
+    justify: JustifyText,
+    linebreak_behavior: BreakLineOn,
+}
+
+/// Fonts are serialized as their asset paths.
+///
+/// Fonts that weren't loaded from a path are serialized as the default font.
+impl Serialize for UiText {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedText {
+            sections: self
+                .sections
+                .iter()
+                .map(|section| SerializedSection {
+                    value: section.value.clone(),
+                    font: section.style.font.path().map(ToString::to_string),
+                    font_size: section.style.font_size,
+                    color: section.style.color,
+                })
+                .collect(),
+            justify: self.justify,
+            linebreak_behavior: self.linebreak_behavior,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Fonts are deserialized as the default font, e.g. for a text spawned from a scene whose
+/// [`UiTextFontPaths`](crate::UiTextFontPaths) reloads them. Use [`UiTextSeed`] to load the
+/// fonts from their paths instead.
+impl<'de> Deserialize<'de> for UiText {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<UiText, D::Error> {
+        SerializedText::deserialize(deserializer).map(|text| text.into_text(|_| Handle::default()))
+    }
+}
+
+impl SerializedText {
+    fn into_text(self, mut font: impl FnMut(Option<String>) -> Handle<Font>) -> UiText {
+        UiText(Text {
+            sections: self
+                .sections
+                .into_iter()
+                .map(|section| TextSection {
+                    value: section.value,
+                    style: TextStyle {
+                        font: font(section.font),
+                        font_size: section.font_size,
+                        color: section.color,
+                    },
+                })
+                .collect(),
+            justify: self.justify,
+            linebreak_behavior: self.linebreak_behavior,
+        })
+    }
+}
+
+/// Deserializes a [`UiText`], loading its fonts from their asset paths.
+///
+/// ```ignore
+/// let text = UiTextSeed(&asset_server).deserialize(&mut ron::Deserializer::from_str(source)?)?;
+/// ```
+#[derive(Clone, Copy)]
+pub struct UiTextSeed<'a>(pub &'a AssetServer);
+
+impl<'de> DeserializeSeed<'de> for UiTextSeed<'_> {
+    type Value = UiText;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<UiText, D::Error> {
+        SerializedText::deserialize(deserializer).map(|text| {
+            text.into_text(|path| path.map(|path| self.0.load(path)).unwrap_or_default())
+        })
+    }
+}
@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+/// Draws a drop shadow behind the glyphs of a [`UiText`](crate::UiText), to keep labels readable
+/// over bright scenes.
+///
+/// The shadow is a copy of every glyph in `color`, moved by `offset` logical pixels on screen,
+/// with y pointing down, whatever the text's rotation. Its alpha is multiplied by the alpha of
+/// each glyph, so fading text fades its shadow too. Baked texts aren't shadowed.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextShadow {
+    pub offset: Vec2,
+    pub color: Color,
+}
+
+impl Default for UiTextShadow {
+    fn default() -> Self {
+        Self {
+            offset: Vec2::new(2., 2.),
+            color: Color::srgba(0., 0., 0., 0.75),
+        }
+    }
+}
+
+impl UiTextShadow {
+    pub fn new(offset: Vec2, color: Color) -> Self {
+        Self { offset, color }
+    }
+}
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+/// Whether a [`UiText`](crate::UiText)'s position is snapped to the physical pixel grid when
+/// it's drawn.
+///
+/// Snapped texts stay crisp but move in whole pixel steps, which makes slow movement jitter.
+/// Texts without the component are snapped.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextSnapping {
+    #[default]
+    Snapped,
+    /// Drawn at its exact position, glyphs can be blurred by sampling between pixels.
+    Smooth,
+    /// Smooth on frames where the text's [`GlobalTransform`] changed and snapped while it's
+    /// still, crisp when resting and smooth when moving.
+    Auto,
+}
+
+impl TextSnapping {
+    /// Whether a text is snapped, `moved` is whether its global transform changed since it was
+    /// last drawn.
+    pub fn snaps(self, moved: bool) -> bool {
+        match self {
+            TextSnapping::Snapped => true,
+            TextSnapping::Smooth => false,
+            TextSnapping::Auto => !moved,
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use std::fmt;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, AsyncReadExt, LoadContext};
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+use serde::Deserialize;
+
+use crate::{SyntheticBold, UiText};
+
+/// A text style loaded from a `.styles.ron` file, so fonts, sizes and colors can be tweaked
+/// without recompiling.
+///
+/// A file holds a map of named styles, each loaded as a labeled asset of the file:
+///
+/// ```ron
+/// {
+///     "title": (
+///         font: Some("Topaz-8.ttf"),
+///         font_size: 48.0,
+///         color: Srgba((red: 1.0, green: 0.8, blue: 0.2, alpha: 1.0)),
+///         synthetic_bold: Some(1.0),
+///     ),
+/// }
+/// ```
+///
+/// ```ignore
+/// let title: Handle<UiTextStyleAsset> = asset_server.load("menu.styles.ron#title");
+/// ```
+///
+/// With hot reloading enabled, texts using a style are updated when its file is saved.
+#[derive(Asset, TypePath, Clone, Debug)]
+pub struct UiTextStyleAsset {
+    /// The default font if the style doesn't name one.
+    pub font: Handle<Font>,
+    pub font_size: f32,
+    pub color: Color,
+    /// Inserts a [`SyntheticBold`] of this strength on the styled entity.
+    pub synthetic_bold: Option<f32>,
+}
+
+impl UiTextStyleAsset {
+    fn apply(&self, style: &mut TextStyle) {
+        style.font = self.font.clone();
+        style.font_size = self.font_size;
+        style.color = self.color;
+    }
+
+    fn matches(&self, style: &TextStyle) -> bool {
+        style.font == self.font && style.font_size == self.font_size && style.color == self.color
+    }
+}
+
+/// The named [`UiTextStyleAsset`]s of a `.styles.ron` file.
+#[derive(Asset, TypePath, Clone, Debug, Default)]
+pub struct UiTextStyleSheet {
+    pub styles: HashMap<String, Handle<UiTextStyleAsset>>,
+}
+
+#[derive(Deserialize)]
+struct SerializedStyle {
+    /// Asset path of the font.
+    #[serde(default)]
+    font: Option<String>,
+    font_size: f32,
+    color: Color,
+    #[serde(default)]
+    synthetic_bold: Option<f32>,
+}
+
+/// Loads `.styles.ron` files into a [`UiTextStyleSheet`] with a labeled [`UiTextStyleAsset`]
+/// for each style.
+#[derive(Default)]
+pub struct UiTextStyleLoader;
+
+#[derive(Debug)]
+pub enum UiTextStyleLoaderError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+}
+
+impl fmt::Display for UiTextStyleLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UiTextStyleLoaderError::Io(error) => write!(f, "Failed to read the styles: {error}"),
+            UiTextStyleLoaderError::Ron(error) => write!(f, "Failed to parse the styles: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for UiTextStyleLoaderError {}
+
+impl AssetLoader for UiTextStyleLoader {
+    type Asset = UiTextStyleSheet;
+    type Settings = ();
+    type Error = UiTextStyleLoaderError;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a (),
+        load_context: &'a mut LoadContext<'_>,
+    ) -> Result<UiTextStyleSheet, UiTextStyleLoaderError> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(UiTextStyleLoaderError::Io)?;
+        let serialized: HashMap<String, SerializedStyle> =
+            ron::de::from_bytes(&bytes).map_err(UiTextStyleLoaderError::Ron)?;
+        let mut sheet = UiTextStyleSheet::default();
+        for (name, style) in serialized {
+            let font = match style.font {
+                Some(path) => load_context.load(path),
+                None => Handle::default(),
+            };
+            let handle = load_context.add_labeled_asset(
+                name.clone(),
+                UiTextStyleAsset {
+                    font,
+                    font_size: style.font_size,
+                    color: style.color,
+                    synthetic_bold: style.synthetic_bold,
+                },
+            );
+            sheet.styles.insert(name, handle);
+        }
+        Ok(sheet)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["styles.ron"]
+    }
+}
+
+/// Styles the sections of the entity's [`UiText`] with [`UiTextStyleAsset`]s.
+///
+/// The styles are applied once loaded and again whenever they're modified, only then is the text
+/// changed and laid out again. A font named by a style is laid out once it has loaded, like any
+/// other font.
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct UiTextStyleRef {
+    /// Style of every section without a style of its own.
+    pub style: Option<Handle<UiTextStyleAsset>>,
+    /// Styles of the sections by index.
+    pub sections: Vec<Option<Handle<UiTextStyleAsset>>>,
+}
+
+impl UiTextStyleRef {
+    pub fn new(style: Handle<UiTextStyleAsset>) -> Self {
+        Self {
+            style: Some(style),
+            sections: Vec::new(),
+        }
+    }
+
+    pub fn with_section(mut self, index: usize, style: Handle<UiTextStyleAsset>) -> Self {
+        if self.sections.len() <= index {
+            self.sections.resize(index + 1, None);
+        }
+        self.sections[index] = Some(style);
+        self
+    }
+
+    /// The style of the section at `index`.
+    pub fn section(&self, index: usize) -> Option<&Handle<UiTextStyleAsset>> {
+        self.sections
+            .get(index)
+            .and_then(Option::as_ref)
+            .or(self.style.as_ref())
+    }
+
+    fn uses_any(&self, ids: &HashSet<AssetId<UiTextStyleAsset>>) -> bool {
+        self.style
+            .iter()
+            .chain(self.sections.iter().flatten())
+            .any(|handle| ids.contains(&handle.id()))
+    }
+}
+
+/// Applies [`UiTextStyleRef`]s that were added or changed, or whose styles loaded or were
+/// modified.
+pub fn apply_ui_text_styles(
+    mut commands: Commands,
+    mut style_events: EventReader<AssetEvent<UiTextStyleAsset>>,
+    styles: Res<Assets<UiTextStyleAsset>>,
+    mut text_query: Query<(
+        Entity,
+        Ref<UiTextStyleRef>,
+        &mut UiText,
+        Option<&mut SyntheticBold>,
+    )>,
+) {
+    let changed_styles: HashSet<AssetId<UiTextStyleAsset>> = style_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Added { id }
+            | AssetEvent::Modified { id }
+            | AssetEvent::LoadedWithDependencies { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+    for (entity, style_ref, mut text, maybe_bold) in &mut text_query {
+        if !(style_ref.is_changed() || style_ref.uses_any(&changed_styles)) {
+            continue;
+        }
+        for index in 0..text.sections.len() {
+            let Some(style) = style_ref
+                .section(index)
+                .and_then(|handle| styles.get(handle))
+            else {
+                continue;
+            };
+            // only touch the text if the style differs, a change would lay it out again
+            if !style.matches(&text.sections[index].style) {
+                style.apply(&mut text.sections[index].style);
+            }
+        }
+
+        let Some(strength) = style_ref
+            .style
+            .as_ref()
+            .and_then(|handle| styles.get(handle))
+            .and_then(|style| style.synthetic_bold)
+        else {
+            continue;
+        };
+        match maybe_bold {
+            Some(mut bold) => {
+                bold.set_if_neq(SyntheticBold(strength));
+            }
+            None => {
+                commands.entity(entity).insert(SyntheticBold(strength));
+            }
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use bevy::prelude::*;
+
+/// Faux styles synthesized for a section of a [`UiText`](crate::UiText) whose font has no
+/// bold or italic face.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Synthesis {
+    /// Draws each glyph a second time with a small horizontal offset.
+    pub faux_bold: bool,
+    /// Shears each glyph.
+    pub faux_italic: bool,
+}
+
+impl Synthesis {
+    pub const BOLD: Self = Self {
+        faux_bold: true,
+        faux_italic: false,
+    };
+    pub const ITALIC: Self = Self {
+        faux_bold: false,
+        faux_italic: true,
+    };
+    pub const BOLD_ITALIC: Self = Self {
+        faux_bold: true,
+        faux_italic: true,
+    };
+
+    /// The faux styles of either.
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            faux_bold: self.faux_bold || other.faux_bold,
+            faux_italic: self.faux_italic || other.faux_italic,
+        }
+    }
+}
+
+/// Per section or whole text faux bold and italic, applied when the glyphs are extracted.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextSectionSynthesis {
+    /// Synthesis for each section by index. Sections without an entry are drawn normally.
+    pub sections: Vec<Synthesis>,
+    /// Synthesis applied to every section in addition to its own entry, e.g. to italicize a
+    /// whole text.
+    pub all_sections: Synthesis,
+    /// Angle in radians that faux italic glyphs lean to the right.
+    pub italic_skew: f32,
+    /// Offset of the second faux bold glyph in physical pixels.
+    pub bold_offset: f32,
+    /// Narrows the bounds used for wrapping by the width the faux italic glyphs lean past
+    /// their advance, so the last glyph of a line doesn't overhang the bounds.
+    pub italic_overhang: bool,
+}
+
+impl Default for UiTextSectionSynthesis {
+    fn default() -> Self {
+        Self {
+            sections: Vec::new(),
+            all_sections: Synthesis::default(),
+            italic_skew: 12f32.to_radians(),
+            bold_offset: 1.,
+            italic_overhang: true,
+        }
+    }
+}
+
+impl UiTextSectionSynthesis {
+    pub fn new(sections: impl IntoIterator<Item = Synthesis>) -> Self {
+        Self {
+            sections: sections.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Applies `synthesis` to every section of the text.
+    pub fn uniform(synthesis: Synthesis) -> Self {
+        Self {
+            all_sections: synthesis,
+            ..Default::default()
+        }
+    }
+
+    /// Leans every section of the text to the right by `skew` radians.
+    pub fn italic(skew: f32) -> Self {
+        Self {
+            italic_skew: skew,
+            ..Self::uniform(Synthesis::ITALIC)
+        }
+    }
+
+    pub fn with_italic_skew(mut self, skew: f32) -> Self {
+        self.italic_skew = skew;
+        self
+    }
+
+    pub fn section(&self, index: usize) -> Synthesis {
+        self.sections
+            .get(index)
+            .copied()
+            .unwrap_or_default()
+            .union(self.all_sections)
+    }
+
+    /// Shear applied to faux italic glyphs, around the glyph's center.
+    #[cfg(feature = "render")]
+    pub(crate) fn italic_shear(&self) -> Mat4 {
+        // y points down, so the top of the glyph moves right
+        Mat4::from_cols(
+            Vec4::X,
+            Vec4::new(-self.italic_skew.tan(), 1., 0., 0.),
+            Vec4::Z,
+            Vec4::W,
+        )
+    }
+
+    /// How far, in the same units as `font_size`, the faux italic sections lean past their advance.
+    pub(crate) fn italic_overhang(&self, sections: &[TextSection]) -> f32 {
+        if !self.italic_overhang {
+            return 0.;
+        }
+        sections
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.section(*index).faux_italic)
+            .map(|(_, section)| 0.5 * section.style.font_size * self.italic_skew.tan())
+            .fold(0., f32::max)
+    }
+}
+
+/// Thickens every glyph of a [`UiText`](crate::UiText) by drawing it again shifted right by up
+/// to this many logical pixels, like a double strike.
+///
+/// The glyph is repeated at every physical pixel of the offset, so the strokes thicken without
+/// gaps and by the same visual amount on any display. Texts with
+/// [`UiTextScaling::Physical`](crate::UiTextScaling::Physical) are thickened in physical pixels.
+/// Combines with the faux bold of [`UiTextSectionSynthesis`].
+#[derive(Component, Clone, Copy, Debug, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SyntheticBold(pub f32);
+
+impl Default for SyntheticBold {
+    fn default() -> Self {
+        Self(0.5)
+    }
+}
+
+#[cfg(feature = "render")]
+impl SyntheticBold {
+    /// Offsets of the extra copies of each glyph, in the text's local pixels. `pixel_size` is
+    /// the size of a physical pixel in the same pixels.
+    pub(crate) fn offsets(&self, pixel_size: f32) -> impl Iterator<Item = f32> {
+        let strength = self.0.max(0.);
+        let count = (strength / pixel_size).ceil() as usize;
+        (1..=count).map(move |step| strength * step as f32 / count as f32)
+    }
+}
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+use bevy::text::{PositionedGlyph, TextLayoutInfo};
+
+use crate::bidi::joined_text;
+use crate::lines::glyph_lines;
+
+/// Positions, in logical pixels from the left of each line, that a tab character in a
+/// [`UiText`](crate::UiText) advances the following glyphs to, e.g. to align the values of a list
+/// of stats.
+///
+/// Each tab moves the next glyph's left edge to the first stop past the end of the glyphs before
+/// it. Tabs past the last stop keep the advance given to them by the font. Stops are meant for
+/// left justified texts, the lines are aligned before the tabs are applied and lines aren't
+/// wrapped again, so a line can end up wider than its bounds.
+#[derive(Component, Clone, Debug, Default, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TabStops(pub Vec<f32>);
+
+impl TabStops {
+    /// Stops every `interval` logical pixels, up to `width`.
+    pub fn every(interval: f32, width: f32) -> Self {
+        let count = if 0. < interval {
+            (width / interval) as usize
+        } else {
+            0
+        };
+        Self((1..=count).map(|index| index as f32 * interval).collect())
+    }
+}
+
+/// Removes the glyphs of tab characters and moves the glyphs after them to the next stops.
+///
+/// `scale_factor` is the one the text was laid out at. Applied before the glyphs are reordered by
+/// [`reorder_bidi_glyphs`](crate::reorder_bidi_glyphs), while every line is still in logical
+/// order. The layout's width grows to fit the moved glyphs.
+pub(crate) fn apply_tab_stops(
+    layout: &mut TextLayoutInfo,
+    sections: &[TextSection],
+    tab_stops: &TabStops,
+    scale_factor: f32,
+) {
+    let (text, section_offsets) = joined_text(sections);
+    if !text.contains('\t') {
+        return;
+    }
+    let text_index =
+        |glyph: &PositionedGlyph| section_offsets[glyph.section_index] + glyph.byte_index;
+    // fonts without a glyph for tabs would draw a missing glyph box
+    layout.glyphs.retain(|glyph| {
+        !text
+            .get(text_index(glyph)..)
+            .is_some_and(|rest| rest.starts_with('\t'))
+    });
+
+    let mut stops: Vec<f32> = tab_stops
+        .iter()
+        .map(|stop| stop * scale_factor)
+        .filter(|stop| stop.is_finite())
+        .collect();
+    stops.sort_by(f32::total_cmp);
+    let mut previous_end = 0;
+    let mut width = layout.logical_size.x;
+    for line in glyph_lines(&layout.glyphs) {
+        let glyphs = &mut layout.glyphs[line];
+        let mut shift = 0.;
+        let mut pen = 0.;
+        for glyph in glyphs.iter_mut() {
+            let start = text_index(glyph);
+            let gap = text.get(previous_end..start).unwrap_or_default();
+            // only the tabs after the line's last newline
+            let gap = gap.rsplit('\n').next().unwrap_or_default();
+            let left = glyph.position.x - 0.5 * glyph.size.x;
+            for _ in gap.matches('\t') {
+                if let Some(stop) = stops.iter().copied().find(|stop| pen < *stop) {
+                    shift = stop - left;
+                    pen = stop;
+                }
+            }
+            glyph.position.x += shift;
+            pen = pen.max(glyph.position.x + 0.5 * glyph.size.x);
+            previous_end = start
+                + text
+                    .get(start..)
+                    .and_then(|rest| rest.chars().next())
+                    .map_or(0, char::len_utf8);
+        }
+        width = width.max(pen);
+    }
+    layout.logical_size.x = width;
+}
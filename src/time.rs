@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+/// The clock the crate's animated texts advance with: [`UiTextTimer`](crate::UiTextTimer),
+/// [`UiTextScramble`](crate::UiTextScramble), [`UiTextFloating`](crate::UiTextFloating),
+/// [`UiTextPool`](crate::UiTextPool) lifetimes, [`WaveText`](crate::WaveText) and
+/// [`ColorCycle`](crate::ColorCycle).
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Resource, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UiTextTimeSource {
+    /// [`Time<Virtual>`], so pausing or slowing down the game does the same to the texts.
+    #[default]
+    Virtual,
+    /// [`Time<Real>`], for texts that keep animating in pause menus.
+    Real,
+}
+
+/// The time from the [`UiTextTimeSource`], for systems animating texts.
+#[derive(SystemParam)]
+pub struct UiTextTime<'w> {
+    source: Res<'w, UiTextTimeSource>,
+    virtual_time: Res<'w, Time<Virtual>>,
+    real_time: Res<'w, Time<Real>>,
+}
+
+impl UiTextTime<'_> {
+    pub fn delta(&self) -> Duration {
+        match *self.source {
+            UiTextTimeSource::Virtual => self.virtual_time.delta(),
+            UiTextTimeSource::Real => self.real_time.delta(),
+        }
+    }
+
+    pub fn delta_seconds(&self) -> f32 {
+        self.delta().as_secs_f32()
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        match *self.source {
+            UiTextTimeSource::Virtual => self.virtual_time.elapsed(),
+            UiTextTimeSource::Real => self.real_time.elapsed(),
+        }
+    }
+
+    pub fn elapsed_seconds(&self) -> f32 {
+        self.elapsed().as_secs_f32()
+    }
+}
@@ -0,0 +1,189 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{UiText, UiTextTime};
+
+/// Whether a [`UiTextTimer`] counts down to zero or up from zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UiTextTimerMode {
+    /// Counts down from the duration and stops at zero.
+    CountDown(Duration),
+    /// Counts up without stopping.
+    CountUp,
+}
+
+impl Default for UiTextTimerMode {
+    fn default() -> Self {
+        Self::CountDown(Duration::from_secs(60))
+    }
+}
+
+/// How a [`UiTextTimer`] is shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimerFormat {
+    /// `MM:SS`, minutes keep counting past an hour.
+    #[default]
+    MinutesSeconds,
+    /// `M:SS.t`, with tenths of a second.
+    MinutesSecondsTenths,
+    /// `HH:MM:SS`.
+    HoursMinutesSeconds,
+}
+
+impl TimerFormat {
+    /// The time in the format's smallest unit, tenths for `MinutesSecondsTenths` and seconds
+    /// otherwise. Rounded up when counting down, so a countdown only shows zero once finished.
+    fn ticks(self, time: Duration, round_up: bool) -> u64 {
+        let tick_nanos = match self {
+            TimerFormat::MinutesSecondsTenths => 100_000_000,
+            _ => 1_000_000_000,
+        };
+        let nanos = time.as_nanos() as u64;
+        if round_up {
+            nanos.div_ceil(tick_nanos)
+        } else {
+            nanos / tick_nanos
+        }
+    }
+
+    fn format(self, ticks: u64) -> String {
+        match self {
+            TimerFormat::MinutesSeconds => format!("{:02}:{:02}", ticks / 60, ticks % 60),
+            TimerFormat::MinutesSecondsTenths => {
+                let seconds = ticks / 10;
+                format!("{}:{:02}.{}", seconds / 60, seconds % 60, ticks % 10)
+            }
+            TimerFormat::HoursMinutesSeconds => format!(
+                "{:02}:{:02}:{:02}",
+                ticks / 3600,
+                ticks / 60 % 60,
+                ticks % 60
+            ),
+        }
+    }
+}
+
+/// Shows a countdown or elapsed time in the first section of a [`UiText`].
+///
+/// The timer advances with the [`UiTextTimeSource`](crate::UiTextTimeSource), so by default it
+/// stops while virtual time is paused. The section is only written when the shown value changes,
+/// once a second for whole second formats. A countdown sends a [`UiTextTimerFinished`] event
+/// when it reaches zero and stops there.
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextTimer {
+    pub mode: UiTextTimerMode,
+    pub format: TimerFormat,
+    /// While a countdown has less than this much time left, the section is drawn in this color.
+    pub warn_below: Option<(Duration, Color)>,
+    elapsed: Duration,
+    finished: bool,
+    /// The value last written to the section.
+    shown_ticks: Option<u64>,
+    /// The section's color from before it was changed to the warning color.
+    normal_color: Option<Color>,
+}
+
+impl UiTextTimer {
+    pub fn countdown(duration: Duration) -> Self {
+        Self {
+            mode: UiTextTimerMode::CountDown(duration),
+            ..Default::default()
+        }
+    }
+
+    pub fn count_up() -> Self {
+        Self {
+            mode: UiTextTimerMode::CountUp,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_format(mut self, format: TimerFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn with_warning(mut self, below: Duration, color: Color) -> Self {
+        self.warn_below = Some((below, color));
+        self
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Time left of a countdown, `None` when counting up.
+    pub fn remaining(&self) -> Option<Duration> {
+        match self.mode {
+            UiTextTimerMode::CountDown(duration) => Some(duration.saturating_sub(self.elapsed)),
+            UiTextTimerMode::CountUp => None,
+        }
+    }
+
+    /// Whether a countdown has reached zero.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Restarts the timer from the beginning.
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.finished = false;
+    }
+}
+
+/// Sent when a countdown [`UiTextTimer`] reaches zero.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UiTextTimerFinished(pub Entity);
+
+pub fn update_ui_text_timers(
+    time: UiTextTime,
+    mut finished_events: EventWriter<UiTextTimerFinished>,
+    mut timer_query: Query<(Entity, &mut UiTextTimer, &mut UiText)>,
+) {
+    for (entity, mut timer, mut text) in &mut timer_query {
+        if !timer.finished {
+            timer.elapsed += time.delta();
+        }
+        let remaining = timer.remaining();
+        if remaining == Some(Duration::ZERO) && !timer.finished {
+            timer.finished = true;
+            finished_events.send(UiTextTimerFinished(entity));
+        }
+
+        let ticks = match remaining {
+            Some(remaining) => timer.format.ticks(remaining, true),
+            None => timer.format.ticks(timer.elapsed, false),
+        };
+        if timer.shown_ticks != Some(ticks) {
+            timer.shown_ticks = Some(ticks);
+            let value = timer.format.format(ticks);
+            UiText::set_section_value(&mut text, 0, &value);
+        }
+
+        let warning = timer
+            .warn_below
+            .filter(|(below, _)| remaining.is_some_and(|remaining| remaining < *below));
+        let Some(current_color) = text.sections.first().map(|section| section.style.color) else {
+            continue;
+        };
+        match (warning, timer.normal_color) {
+            (Some((_, warn_color)), normal_color) => {
+                if normal_color.is_none() {
+                    timer.normal_color = Some(current_color);
+                }
+                UiText::set_section_color(&mut text, 0, warn_color);
+            }
+            (None, Some(normal_color)) => {
+                timer.normal_color = None;
+                UiText::set_section_color(&mut text, 0, normal_color);
+            }
+            (None, None) => {}
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+/// Color multiplied into the color of every glyph of a [`UiText`](crate::UiText), on top of
+/// its sections' colors.
+///
+/// Changing the tint doesn't relayout the text.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextTint(pub Color);
+
+impl Default for TextTint {
+    fn default() -> Self {
+        Self(Color::WHITE)
+    }
+}
+
+#[cfg(feature = "render")]
+impl TextTint {
+    pub(crate) fn apply(tint: Option<&Self>, color: LinearRgba) -> LinearRgba {
+        let Some(tint) = tint else {
+            return color;
+        };
+        let tint = LinearRgba::from(tint.0);
+        LinearRgba::new(
+            color.red * tint.red,
+            color.green * tint.green,
+            color.blue * tint.blue,
+            color.alpha * tint.alpha,
+        )
+    }
+}
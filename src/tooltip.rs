@@ -0,0 +1,103 @@
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use bevy::text::TextLayoutInfo;
+use bevy::window::PrimaryWindow;
+
+use crate::{anchor_point, is_valid_scale_factor};
+
+/// Positions a [`UiText`](crate::UiText) next to the primary window's cursor every frame.
+///
+/// The text is placed `offset` logical pixels from the cursor, on the other side of the cursor
+/// on each axis where it would run past the window edges inset by `clamp_margin`. It isn't drawn
+/// while the cursor is outside the window. Show and hide the tooltip with its [`Visibility`].
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextTooltip {
+    /// Offset from the cursor to the nearest corner of the text, `y` points down.
+    pub offset: Vec2,
+    /// Distance kept between the text and the window edges.
+    pub clamp_margin: UiRect,
+}
+
+impl Default for UiTextTooltip {
+    fn default() -> Self {
+        Self {
+            offset: Vec2::splat(16.),
+            clamp_margin: UiRect::all(Val::Px(4.)),
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn position_ui_text_tooltips(
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    default_ui_camera: DefaultUiCamera,
+    camera_query: Query<&Camera>,
+    mut tooltip_query: Query<(
+        &UiTextTooltip,
+        &TextLayoutInfo,
+        Option<&TargetCamera>,
+        Option<&Anchor>,
+        &mut Transform,
+    )>,
+) {
+    let Ok(window) = window_query.get_single() else {
+        return;
+    };
+    let Some(cursor) = window.cursor_position() else {
+        return;
+    };
+    let scale_factor = window.scale_factor();
+    if !is_valid_scale_factor(scale_factor) {
+        return;
+    }
+    let window_size = window.size();
+    let resolve = |val: Val| val.resolve(window_size.x, window_size).unwrap_or(0.);
+
+    for (tooltip, text_layout, maybe_camera, maybe_anchor, mut transform) in &mut tooltip_query {
+        let viewport_min = maybe_camera
+            .map(TargetCamera::entity)
+            .or(default_ui_camera.get())
+            .and_then(|camera_entity| camera_query.get(camera_entity).ok())
+            .and_then(Camera::logical_viewport_rect)
+            .map_or(Vec2::ZERO, |viewport| viewport.min);
+        let half_size = 0.5 * text_layout.logical_size / scale_factor;
+        let margin = &tooltip.clamp_margin;
+        let min = Vec2::new(resolve(margin.left), resolve(margin.top)) + half_size;
+        let max =
+            window_size - Vec2::new(resolve(margin.right), resolve(margin.bottom)) - half_size;
+
+        let mut center = cursor + tooltip.offset + half_size * tooltip.offset.signum();
+        for axis in 0..2 {
+            if center[axis] < min[axis] || max[axis] < center[axis] {
+                center[axis] = 2. * cursor[axis] - center[axis];
+            }
+        }
+        // texts too large to fit between the margins stick to the top left margins
+        let center = center.min(max).max(min);
+
+        let anchor = center - half_size + anchor_point(maybe_anchor, 2. * half_size);
+        let translation = (anchor - viewport_min).extend(transform.translation.z);
+        if transform.translation != translation {
+            transform.translation = translation;
+        }
+    }
+}
+
+/// Hides tooltips while the cursor is outside the primary window, without touching their
+/// [`Visibility`].
+pub fn hide_ui_text_tooltips(
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut tooltip_query: Query<&mut ViewVisibility, With<UiTextTooltip>>,
+) {
+    if window_query
+        .get_single()
+        .is_ok_and(|window| window.cursor_position().is_some())
+    {
+        return;
+    }
+    for mut view_visibility in &mut tooltip_query {
+        *view_visibility = ViewVisibility::HIDDEN;
+    }
+}
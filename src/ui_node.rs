@@ -0,0 +1,66 @@
+use bevy::math::Affine3A;
+use bevy::prelude::*;
+
+use crate::UiText;
+
+/// Where on its UI node ancestor a [`UiText`] is positioned from, relative to the node's size.
+///
+/// `Vec2::ZERO` (the default) is the node's top left corner, `Vec2::ONE` its bottom right corner.
+/// The text's [`Transform`] is an offset from this point in logical pixels.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiNodeTextOrigin(pub Vec2);
+
+impl UiNodeTextOrigin {
+    pub const TOP_LEFT: Self = Self(Vec2::ZERO);
+    pub const CENTER: Self = Self(Vec2::splat(0.5));
+    pub const BOTTOM_RIGHT: Self = Self(Vec2::ONE);
+}
+
+/// Positions texts with a UI node ancestor relative to the node's computed rect.
+///
+/// Transform propagation places children relative to the center of a node, this moves them to
+/// the node's [`UiNodeTextOrigin`] instead. Runs after transform propagation and only modifies
+/// the texts' [`GlobalTransform`]s, which are recomputed from the local transforms every frame.
+#[allow(clippy::type_complexity)]
+pub fn position_ui_node_texts(
+    mut text_query: Query<
+        (
+            &Parent,
+            &Transform,
+            &mut GlobalTransform,
+            Option<&UiNodeTextOrigin>,
+        ),
+        With<UiText>,
+    >,
+    node_query: Query<(&Node, &GlobalTransform), Without<UiText>>,
+    ancestor_query: Query<(&Transform, Option<&Parent>), Without<UiText>>,
+) {
+    for (parent, transform, mut global_transform, maybe_origin) in &mut text_query {
+        // transform from the text's local space to the space of its nearest UI node ancestor
+        let mut affine = transform.compute_affine();
+        let mut ancestor = parent.get();
+        let nearest_node = loop {
+            if let Ok(node) = node_query.get(ancestor) {
+                break Some(node);
+            }
+            match ancestor_query.get(ancestor) {
+                Ok((ancestor_transform, Some(parent))) => {
+                    affine = ancestor_transform.compute_affine() * affine;
+                    ancestor = parent.get();
+                }
+                _ => break None,
+            }
+        };
+        let Some((node, node_transform)) = nearest_node else {
+            continue;
+        };
+        let origin = maybe_origin.copied().unwrap_or_default();
+        let offset = (origin.0 - Vec2::splat(0.5)) * node.size();
+        // unchanged texts aren't marked changed, e.g. for `TextSnapping::Auto`
+        global_transform.set_if_neq(GlobalTransform::from(
+            node_transform.affine() * Affine3A::from_translation(offset.extend(0.)) * affine,
+        ));
+    }
+}
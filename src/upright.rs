@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+
+/// Keeps a [`UiText`](crate::UiText) upright when an ancestor's transform rotates it, e.g. for
+/// labels on a spinning card or a compass rose.
+///
+/// The text still follows its [`GlobalTransform`]'s translation and scale, only the rotation is
+/// dropped when the text is drawn, so its anchor stays where the hierarchy puts it. The
+/// transforms themselves aren't changed.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiTextKeepUpright {
+    /// Keeps the rotation of the text's own [`Transform`], so the text can be drawn at a fixed
+    /// angle whatever its ancestors' rotations.
+    pub keep_local_rotation: bool,
+}
+
+impl UiTextKeepUpright {
+    /// Drops the rotation of the text's ancestors from its global transform, keeping the
+    /// rotation of its own `transform` if `keep_local_rotation` is set.
+    pub fn apply(
+        &self,
+        global_transform: &GlobalTransform,
+        transform: &Transform,
+    ) -> GlobalTransform {
+        let (scale, _, translation) = global_transform.to_scale_rotation_translation();
+        let rotation = if self.keep_local_rotation {
+            transform.rotation
+        } else {
+            Quat::IDENTITY
+        };
+        GlobalTransform::from(Transform {
+            translation,
+            rotation,
+            scale,
+        })
+    }
+}
+
+/// The global transform a text is drawn with, without its ancestors' rotation if it has a
+/// [`UiTextKeepUpright`].
+pub(crate) fn drawn_global_transform(
+    global_transform: &GlobalTransform,
+    keep_upright: Option<(&UiTextKeepUpright, &Transform)>,
+) -> GlobalTransform {
+    match keep_upright {
+        Some((keep_upright, transform)) => keep_upright.apply(global_transform, transform),
+        None => *global_transform,
+    }
+}
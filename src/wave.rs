@@ -0,0 +1,37 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+/// Moves the glyphs of a [`UiText`](crate::UiText) up and down in a wave.
+///
+/// The glyphs are offset when they're extracted, so the wave doesn't relayout the text.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WaveText {
+    /// Largest offset of a glyph from its position, in the same units as the text's
+    /// [`Transform`].
+    pub amplitude: f32,
+    /// Wave cycles per glyph.
+    pub frequency: f32,
+    /// Wave cycles per second.
+    pub speed: f32,
+}
+
+impl Default for WaveText {
+    fn default() -> Self {
+        Self {
+            amplitude: 4.,
+            frequency: 0.1,
+            speed: 1.,
+        }
+    }
+}
+
+impl WaveText {
+    /// Offset of the glyph at `index` at `time` seconds, `y` points down.
+    pub fn offset(&self, index: usize, time: f32) -> Vec2 {
+        let phase = TAU * (self.speed * time - self.frequency * index as f32);
+        Vec2::new(0., self.amplitude * phase.sin())
+    }
+}
@@ -0,0 +1,131 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy::text::Text2dBounds;
+use bevy_mod_ui_independent_text::*;
+use common::*;
+
+const WORDS: &str = "one two three four five six";
+
+fn computed_size(app: &App, entity: Entity) -> UiTextComputedSize {
+    *app.world().get(entity).unwrap()
+}
+
+/// Lays out `WORDS` with the given components, returning its size.
+fn laid_out(scale_factor: f32, components: impl Bundle) -> UiTextComputedSize {
+    let mut app = app(scale_factor);
+    let font = font(&mut app);
+    let entity = spawn_text(
+        &mut app,
+        UiText::from_section(WORDS, style(&font, 16.)),
+        components,
+    );
+    app.update();
+    computed_size(&app, entity)
+}
+
+fn bounds(width: f32) -> UiTextBounds {
+    UiTextBounds {
+        size: Vec2::new(width, f32::INFINITY),
+    }
+}
+
+#[test]
+fn bounds_wrap_in_logical_pixels() {
+    let unbounded = laid_out(1., ());
+    assert_eq!(unbounded.lines, 1);
+    let width = 0.5 * unbounded.size.x;
+    for scale_factor in [1., 2.] {
+        let mut app = app(scale_factor);
+        let font = font(&mut app);
+        let entity = spawn_text(
+            &mut app,
+            UiText::from_section(WORDS, style(&font, 16.)),
+            bounds(width),
+        );
+        app.update();
+        assert_eq!(computed_size(&app, entity).lines, 2);
+        let right = layout(&app, entity)
+            .glyphs
+            .iter()
+            .map(|glyph| glyph.position.x + 0.5 * glyph.size.x)
+            .fold(0., f32::max);
+        assert!(right / scale_factor <= width);
+    }
+}
+
+#[test]
+fn text_2d_bounds_are_used_while_ui_text_bounds_are_unbounded() {
+    let width = 0.5 * laid_out(1., ()).size.x;
+    let expected = laid_out(1., bounds(width));
+    let fallback = laid_out(
+        1.,
+        Text2dBounds {
+            size: Vec2::new(width, f32::INFINITY),
+        },
+    );
+    assert_eq!(fallback, expected);
+}
+
+#[test]
+fn ui_text_bounds_take_precedence_over_text_2d_bounds() {
+    let unbounded = laid_out(1., ());
+    let both = laid_out(
+        1.,
+        (
+            bounds(unbounded.size.x + 1.),
+            Text2dBounds {
+                size: Vec2::new(0.5 * unbounded.size.x, f32::INFINITY),
+            },
+        ),
+    );
+    assert_eq!(both, unbounded);
+}
+
+#[test]
+fn text_2d_bounds_convert_to_the_same_size() {
+    let size = Vec2::new(120., 40.);
+    assert_eq!(UiTextBounds::from(Text2dBounds { size }).size, size);
+}
+
+#[test]
+fn max_bounds_lay_out_like_no_bounds() {
+    for scale_factor in [0.5, 1., 2.] {
+        for justify in [JustifyText::Left, JustifyText::Center, JustifyText::Right] {
+            let glyphs = |components: Option<(UiTextBounds, Text2dBounds)>| {
+                let mut app = app(scale_factor);
+                let font = font(&mut app);
+                let mut text = UiText::from_section("one two\nthree", style(&font, 16.));
+                text.justify = justify;
+                let entity = spawn_text(&mut app, text, ());
+                if let Some(components) = components {
+                    app.world_mut().entity_mut(entity).insert(components);
+                }
+                app.update();
+                let text_layout = layout(&app, entity);
+                let positions: Vec<Vec2> = text_layout
+                    .glyphs
+                    .iter()
+                    .map(|glyph| glyph.position)
+                    .collect();
+                (text_layout.logical_size, positions)
+            };
+            let unbounded = glyphs(None);
+            assert!(unbounded.1.iter().all(|position| position.is_finite()));
+            let max = UiTextBounds {
+                size: Vec2::splat(f32::MAX),
+            };
+            assert_eq!(
+                glyphs(Some((max, Text2dBounds::UNBOUNDED))),
+                unbounded,
+                "{justify:?} at {scale_factor}"
+            );
+            // only the 2d bounds, used while the `UiTextBounds` are unbounded
+            assert_eq!(
+                glyphs(Some((UiTextBounds::default(), Text2dBounds::UNBOUNDED))),
+                unbounded,
+                "{justify:?} at {scale_factor}"
+            );
+        }
+    }
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_mod_ui_independent_text::*;
+use common::*;
+
+fn text() -> UiText {
+    UiText::from_sections([
+        TextSection::new("Score: ", TextStyle::default()),
+        TextSection::new("10", TextStyle::default()),
+    ])
+}
+
+/// Whether editing the text with `edit` marks it as changed.
+fn marks_changed(edit: impl FnOnce(&mut Mut<UiText>)) -> bool {
+    let mut world = World::new();
+    let entity = world.spawn(text()).id();
+    let spawned = world
+        .entity(entity)
+        .get_ref::<UiText>()
+        .unwrap()
+        .last_changed();
+    world.increment_change_tick();
+    edit(&mut world.get_mut::<UiText>(entity).unwrap());
+    world
+        .entity(entity)
+        .get_ref::<UiText>()
+        .unwrap()
+        .last_changed()
+        != spawned
+}
+
+#[test]
+fn identical_values_leave_the_change_tick() {
+    assert!(!marks_changed(|text| {
+        assert!(!UiText::set_section_value(text, 1, "10"));
+        assert!(!UiText::set_section_color(text, 0, Color::WHITE));
+        assert!(!UiText::set_section_style(text, 1, TextStyle::default()));
+        assert!(!UiText::update_sections(text, |sections| {
+            sections[1].value = "10".to_string();
+        }));
+    }));
+}
+
+#[test]
+fn out_of_range_sections_are_ignored() {
+    assert!(!marks_changed(|text| {
+        assert!(!UiText::set_section_value(text, 2, "20"));
+        assert!(!UiText::set_section_color(text, 2, Color::BLACK));
+        assert!(!UiText::set_section_style(text, 2, TextStyle::default()));
+    }));
+}
+
+#[test]
+fn different_values_advance_the_change_tick() {
+    assert!(marks_changed(|text| {
+        assert!(UiText::set_section_value(text, 1, "20"));
+    }));
+    assert!(marks_changed(|text| {
+        assert!(UiText::set_section_color(text, 0, Color::BLACK));
+    }));
+    assert!(marks_changed(|text| {
+        assert!(UiText::update_sections(text, |sections| {
+            sections.pop();
+        }));
+    }));
+}
+
+#[test]
+fn unchanged_score_isnt_relaid_out() {
+    let mut app = app(1.);
+    let font = font(&mut app);
+    spawn_text(&mut app, UiText::from_section("10", style(&font, 16.)), ());
+    app.add_systems(Update, |mut query: Query<&mut UiText>| {
+        for mut text in &mut query {
+            UiText::set_section_value(&mut text, 0, "10");
+        }
+    });
+    app.update();
+    let mut relaid_out = app.world_mut().query_filtered::<(), With<JustRelaidOut>>();
+    assert_eq!(relaid_out.iter(app.world()).count(), 1);
+    app.update();
+    assert_eq!(relaid_out.iter(app.world()).count(), 0);
+}
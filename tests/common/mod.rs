@@ -0,0 +1,111 @@
+//! Headless app for the integration tests, laying out and extracting texts without a renderer.
+
+#![allow(dead_code)]
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::render::camera::CameraPlugin;
+use bevy::render::view::VisibilityPlugin;
+use bevy::render::MainWorld;
+use bevy::text::TextPlugin;
+use bevy::ui::{ExtractedUiNode, ExtractedUiNodes};
+use bevy::window::{PrimaryWindow, WindowResolution};
+use bevy_mod_ui_independent_text::*;
+
+/// An app with a 800x600 primary window at `scale_factor`, a 2d camera and the crate's plugin.
+pub fn app(scale_factor: f32) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        TransformPlugin,
+        HierarchyPlugin,
+        AssetPlugin::default(),
+        WindowPlugin {
+            primary_window: Some(Window {
+                resolution: WindowResolution::new(800., 600.)
+                    .with_scale_factor_override(scale_factor),
+                ..default()
+            }),
+            ..default()
+        },
+        bevy::input::InputPlugin,
+        ImagePlugin::default(),
+        CameraPlugin,
+        VisibilityPlugin,
+        TextPlugin,
+        IndependentTextPlugin,
+    ))
+    .init_asset::<TextureAtlasLayout>()
+    .init_asset::<Mesh>()
+    // registered by the `ViewPlugin` of a full app
+    .register_type::<Visibility>()
+    .register_type::<InheritedVisibility>()
+    .register_type::<ViewVisibility>();
+    app.world_mut().spawn(Camera2dBundle::default());
+    app
+}
+
+/// The Topaz font the examples use.
+pub fn font(app: &mut App) -> Handle<Font> {
+    let font = Font::try_from_bytes(include_bytes!("../../assets/Topaz-8.ttf").to_vec())
+        .expect("the example font is valid");
+    app.world_mut().resource_mut::<Assets<Font>>().add(font)
+}
+
+pub fn style(font: &Handle<Font>, font_size: f32) -> TextStyle {
+    TextStyle {
+        font: font.clone(),
+        font_size,
+        color: Color::WHITE,
+    }
+}
+
+/// Spawns a text at the origin, `components` replace those of the bundle.
+pub fn spawn_text(app: &mut App, text: UiText, components: impl Bundle) -> Entity {
+    app.world_mut()
+        .spawn(IndependentTextBundle { text, ..default() })
+        .insert(components)
+        .id()
+}
+
+pub fn layout(app: &App, entity: Entity) -> &bevy::text::TextLayoutInfo {
+    app.world().get(entity).expect("texts have a layout")
+}
+
+/// Sets the primary window's scale factor, sending the event texts relayout on.
+pub fn set_scale_factor(app: &mut App, scale_factor: f32) {
+    let world = app.world_mut();
+    let mut windows = world.query_filtered::<(Entity, &mut Window), With<PrimaryWindow>>();
+    let (window, mut primary) = windows.single_mut(world);
+    primary
+        .resolution
+        .set_scale_factor_override(Some(scale_factor));
+    world.send_event(bevy::window::WindowScaleFactorChanged {
+        window,
+        scale_factor: scale_factor as f64,
+    });
+}
+
+/// Runs the crate's extraction against the app's world, returning the extracted nodes.
+///
+/// The app's [`UiTextExtractStats`] are shared with the extraction like the
+/// [`IndependentTextDiagnosticsPlugin`] shares them with the render app.
+pub fn extract(app: &mut App) -> Vec<(Entity, ExtractedUiNode)> {
+    let mut render_world = World::new();
+    render_world.init_resource::<ExtractedUiNodes>();
+    if let Some(stats) = app.world().get_resource::<UiTextExtractStats>() {
+        render_world.insert_resource(stats.clone());
+    }
+    render_world.init_resource::<MainWorld>();
+    std::mem::swap(
+        app.world_mut(),
+        &mut render_world.resource_mut::<MainWorld>(),
+    );
+    render_world.run_system_once(extract_text_sprite);
+    std::mem::swap(
+        app.world_mut(),
+        &mut render_world.resource_mut::<MainWorld>(),
+    );
+    let mut extracted = render_world.resource_mut::<ExtractedUiNodes>();
+    extracted.uinodes.drain().collect()
+}
@@ -0,0 +1,62 @@
+mod common;
+
+use bevy::diagnostic::{DiagnosticPath, DiagnosticsStore};
+use bevy::prelude::*;
+use bevy_mod_ui_independent_text::*;
+use common::*;
+
+fn measurement(app: &App, path: &DiagnosticPath) -> f64 {
+    app.world()
+        .resource::<DiagnosticsStore>()
+        .get_measurement(path)
+        .unwrap_or_else(|| panic!("no measurement of {path}"))
+        .value
+}
+
+#[test]
+fn counters_follow_the_spawned_texts() {
+    let mut app = app(1.);
+    app.add_plugins(IndependentTextDiagnosticsPlugin);
+    let font = font(&mut app);
+    app.update();
+    assert_eq!(
+        measurement(&app, &IndependentTextDiagnosticsPlugin::VISIBLE_TEXTS),
+        0.
+    );
+    assert_eq!(
+        measurement(&app, &IndependentTextDiagnosticsPlugin::RELAYOUTS),
+        0.
+    );
+
+    for value in ["one", "two", "three"] {
+        spawn_text(
+            &mut app,
+            UiText::from_section(value, style(&font, 16.)),
+            Transform::from_xyz(100., 50., 0.),
+        );
+    }
+    app.update();
+    assert_eq!(
+        measurement(&app, &IndependentTextDiagnosticsPlugin::VISIBLE_TEXTS),
+        3.
+    );
+    assert_eq!(
+        measurement(&app, &IndependentTextDiagnosticsPlugin::RELAYOUTS),
+        3.
+    );
+    assert!(0. < measurement(&app, &IndependentTextDiagnosticsPlugin::LAYOUT_TIME));
+
+    // extraction runs after the update, its count is measured in the next one
+    let extracted = extract(&mut app).len();
+    assert_eq!(extracted, 11);
+    app.update();
+    assert_eq!(
+        measurement(&app, &IndependentTextDiagnosticsPlugin::EXTRACTED_GLYPHS),
+        extracted as f64
+    );
+    // nothing changed, so nothing was laid out again
+    assert_eq!(
+        measurement(&app, &IndependentTextDiagnosticsPlugin::RELAYOUTS),
+        0.
+    );
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_mod_ui_independent_text::*;
+use common::*;
+
+fn translations(nodes: &[(Entity, bevy::ui::ExtractedUiNode)]) -> Vec<Vec2> {
+    let mut translations: Vec<Vec2> = nodes
+        .iter()
+        .map(|(_, node)| node.transform.w_axis.truncate().truncate())
+        .collect();
+    translations.sort_by(|a, b| a.x.total_cmp(&b.x));
+    translations
+}
+
+#[test]
+fn texts_without_a_layout_are_not_extracted() {
+    let mut app = app(1.);
+    // a font that never loads, so the text is never laid out
+    let font = Handle::weak_from_u128(0x5eed);
+    spawn_text(
+        &mut app,
+        UiText::from_section("waiting", style(&font, 16.)),
+        // drawn around the text's `logical_size`, which isn't known yet
+        UiTextBackground::new(Color::BLACK, UiRect::all(Val::Px(4.))),
+    );
+    app.update();
+    assert!(extract(&mut app).is_empty());
+}
+
+#[test]
+fn first_extracted_frame_is_centered() {
+    let mut app = app(1.);
+    let font = font(&mut app);
+    let translation = Vec2::new(100., 50.);
+    let entity = spawn_text(
+        &mut app,
+        UiText::from_section("centered", style(&font, 16.)),
+        Transform::from_translation(translation.extend(0.)),
+    );
+    app.update();
+    let first_frame = translations(&extract(&mut app));
+
+    let text_layout = layout(&app, entity);
+    let mut expected: Vec<Vec2> = text_layout
+        .glyphs
+        .iter()
+        .map(|glyph| translation + glyph.position - 0.5 * text_layout.logical_size)
+        .collect();
+    expected.sort_by(|a, b| a.x.total_cmp(&b.x));
+    assert_eq!(first_frame.len(), 8);
+    for (extracted, expected) in first_frame.iter().zip(&expected) {
+        // within the rounding of the text's position to the pixel grid
+        assert!(
+            extracted.distance(*expected) <= 0.5,
+            "{extracted} {expected}"
+        );
+    }
+
+    app.update();
+    assert_eq!(translations(&extract(&mut app)), first_frame);
+}
+
+#[test]
+fn transparent_glyphs_are_not_extracted() {
+    let mut app = app(1.);
+    let font = font(&mut app);
+    let mut hidden = style(&font, 16.);
+    hidden.color = Color::NONE;
+    spawn_text(
+        &mut app,
+        UiText::from_sections([
+            TextSection::new("shown", style(&font, 16.)),
+            TextSection::new("hidden", hidden),
+        ]),
+        Transform::from_xyz(100., 50., 0.),
+    );
+    let faded = spawn_text(
+        &mut app,
+        UiText::from_section("faded", style(&font, 16.)),
+        (Transform::from_xyz(100., 100., 0.), UiTextAlpha(0.)),
+    );
+    app.update();
+    assert_eq!(extract(&mut app).len(), 5);
+
+    app.world_mut().entity_mut(faded).insert(UiTextAlpha(0.5));
+    assert_eq!(extract(&mut app).len(), 10);
+}
@@ -0,0 +1,95 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use bevy_mod_ui_independent_text::*;
+use common::*;
+
+const JUSTIFIES: [JustifyText; 3] = [JustifyText::Left, JustifyText::Center, JustifyText::Right];
+
+const ANCHORS: [Anchor; 9] = [
+    Anchor::Center,
+    Anchor::BottomLeft,
+    Anchor::BottomCenter,
+    Anchor::BottomRight,
+    Anchor::CenterLeft,
+    Anchor::CenterRight,
+    Anchor::TopLeft,
+    Anchor::TopCenter,
+    Anchor::TopRight,
+];
+
+/// Lays out and extracts a text with a wide first and a short second line, returning the size
+/// of its layout and the positions of the glyphs of each line, left to right.
+fn lines(justify: JustifyText, anchor: Anchor) -> (Vec2, [Vec<Vec2>; 2]) {
+    let mut app = app(1.);
+    let font = font(&mut app);
+    let mut text = UiText::from_section("a wider line\nshort", style(&font, 16.));
+    text.justify = justify;
+    let entity = spawn_text(
+        &mut app,
+        text,
+        (
+            anchor,
+            TextSnapping::Smooth,
+            Transform::from_xyz(100., 50., 0.),
+        ),
+    );
+    app.update();
+    let mut glyphs: Vec<Vec2> = extract(&mut app)
+        .iter()
+        .map(|(_, node)| node.transform.w_axis.truncate().truncate())
+        .collect();
+    glyphs.sort_by(|a, b| a.y.total_cmp(&b.y));
+    assert_eq!(glyphs.len(), 15);
+    let mut short = glyphs.split_off(10);
+    glyphs.sort_by(|a, b| a.x.total_cmp(&b.x));
+    short.sort_by(|a, b| a.x.total_cmp(&b.x));
+    (layout(&app, entity).logical_size, [glyphs, short])
+}
+
+fn assert_near(a: Vec2, b: Vec2, tolerance: f32) {
+    assert!(a.abs_diff_eq(b, tolerance), "{a} != {b}");
+}
+
+#[test]
+fn anchors_place_the_whole_layout_for_every_justify() {
+    for justify in JUSTIFIES {
+        let (size, centered) = lines(justify, Anchor::Center);
+        for anchor in ANCHORS {
+            let (_, anchored) = lines(justify, anchor);
+            // anchors point up, UI coordinates point down
+            let anchor = anchor.as_vec();
+            let offset = Vec2::new(-anchor.x, anchor.y) * size;
+            for (line, centered_line) in anchored.iter().zip(&centered) {
+                for (glyph, centered_glyph) in line.iter().zip(centered_line) {
+                    assert_near(*glyph, *centered_glyph + offset, 1e-3);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn justify_places_the_lines_within_the_layout() {
+    for anchor in ANCHORS {
+        let (_, [left_wide, left_short]) = lines(JustifyText::Left, anchor);
+        let (_, [center_wide, center_short]) = lines(JustifyText::Center, anchor);
+        let (_, [right_wide, right_short]) = lines(JustifyText::Right, anchor);
+        // the widest line spans the layout whatever the justify, within a pixel as glyphs are
+        // rounded to the pixel grid relative to where their line starts
+        for (left, (center, right)) in left_wide.iter().zip(center_wide.iter().zip(&right_wide)) {
+            assert_near(*left, *center, 1.);
+            assert_near(*left, *right, 1.);
+        }
+        // the short line moves by half the free space when centered and all of it when right
+        // justified
+        let shift = right_short[0].x - left_short[0].x;
+        assert!(0. < shift);
+        for (left, (center, right)) in left_short.iter().zip(center_short.iter().zip(&right_short))
+        {
+            assert_near(*center, *left + Vec2::X * 0.5 * shift, 1.);
+            assert_near(*right, *left + Vec2::X * shift, 1.);
+        }
+    }
+}
@@ -0,0 +1,122 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy::text::BreakLineOn;
+use bevy_mod_ui_independent_text::*;
+use common::*;
+
+/// Lays out `value` with the linebreak behavior and bounds width, returning the number of lines.
+fn line_count(value: &str, linebreak_behavior: BreakLineOn, width: f32) -> usize {
+    line_count_and_right(value, linebreak_behavior, width).0
+}
+
+const MODES: [BreakLineOn; 3] = [
+    BreakLineOn::WordBoundary,
+    BreakLineOn::AnyCharacter,
+    BreakLineOn::NoWrap,
+];
+
+#[test]
+fn newlines_break_in_every_mode() {
+    for mode in MODES {
+        assert_eq!(line_count("first\nsecond", mode, f32::INFINITY), 2);
+        assert_eq!(line_count("one\ntwo\nthree", mode, 1000.), 3);
+    }
+}
+
+#[test]
+fn empty_paragraphs_are_kept() {
+    // the empty line between the paragraphs has no glyphs but moves the second one down
+    let mut app = app(1.);
+    let font = font(&mut app);
+    let single = spawn_text(
+        &mut app,
+        UiText::from_section("first\nsecond", style(&font, 16.)),
+        (),
+    );
+    let double = spawn_text(
+        &mut app,
+        UiText::from_section("first\n\nsecond", style(&font, 16.)),
+        (),
+    );
+    app.update();
+    let height = |entity| {
+        app.world()
+            .get::<UiTextComputedSize>(entity)
+            .unwrap()
+            .size
+            .y
+    };
+    assert_eq!(height(double), 1.5 * height(single));
+}
+
+#[test]
+fn lines_wrap_only_with_bounds_and_word_boundaries() {
+    let value = "soft wrapping of a long line";
+    assert_eq!(
+        line_count(value, BreakLineOn::WordBoundary, f32::INFINITY),
+        1
+    );
+    assert!(1 < line_count(value, BreakLineOn::WordBoundary, 100.));
+    assert_eq!(line_count(value, BreakLineOn::NoWrap, 100.), 1);
+}
+
+#[test]
+fn newlines_and_soft_wraps_combine() {
+    let value = "soft wrapping of a long line\nsoft wrapping of a long line";
+    let wrapped = line_count(
+        "soft wrapping of a long line",
+        BreakLineOn::WordBoundary,
+        100.,
+    );
+    assert_eq!(
+        line_count(value, BreakLineOn::WordBoundary, 100.),
+        2 * wrapped
+    );
+    assert_eq!(line_count(value, BreakLineOn::NoWrap, 100.), 2);
+}
+
+/// Lays out `value` with the linebreak behavior and bounds width, returning the number of lines
+/// and the right edge of the rightmost glyph.
+fn line_count_and_right(value: &str, linebreak_behavior: BreakLineOn, width: f32) -> (usize, f32) {
+    let mut app = app(1.);
+    let font = font(&mut app);
+    let mut text = UiText::from_section(value, style(&font, 16.));
+    text.linebreak_behavior = linebreak_behavior;
+    let entity = spawn_text(
+        &mut app,
+        text,
+        UiTextBounds {
+            size: Vec2::new(width, f32::INFINITY),
+        },
+    );
+    app.update();
+    let right = layout(&app, entity)
+        .glyphs
+        .iter()
+        .map(|glyph| glyph.position.x + 0.5 * glyph.size.x)
+        .fold(0., f32::max);
+    let lines = app.world().get::<UiTextComputedSize>(entity).unwrap().lines;
+    (lines, right)
+}
+
+#[test]
+fn any_character_breaks_mid_word_within_the_bounds() {
+    let word = "abcdefghijklmnopqrstuvwxyz";
+    let (lines, right) = line_count_and_right(word, BreakLineOn::WordBoundary, 100.);
+    // a word wider than the bounds overflows on its own line
+    assert_eq!(lines, 1);
+    assert!(100. < right);
+
+    let (lines, right) = line_count_and_right(word, BreakLineOn::AnyCharacter, 100.);
+    assert!(2 < lines);
+    assert!(right <= 100.);
+}
+
+#[test]
+fn no_wrap_ignores_the_bounds_width() {
+    let (lines, right) =
+        line_count_and_right("soft wrapping of a long line", BreakLineOn::NoWrap, 50.);
+    assert_eq!(lines, 1);
+    assert!(50. < right);
+}
@@ -0,0 +1,172 @@
+#![cfg(feature = "material")]
+
+mod common;
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::render::render_resource::{AsBindGroup, Shader};
+use bevy::render::MainWorld;
+use bevy::ui::ExtractedUiMaterialNodes;
+use bevy_mod_ui_independent_text::*;
+use common::*;
+
+#[derive(Asset, TypePath, AsBindGroup, Clone, Debug, Default)]
+struct GlyphMaterial {
+    #[uniform(0)]
+    threshold: f32,
+    glyph: Option<UiTextMaterialGlyph>,
+}
+
+impl UiMaterial for GlyphMaterial {}
+
+impl UiTextGlyphMaterial for GlyphMaterial {
+    fn set_glyph(&mut self, glyph: &UiTextMaterialGlyph) {
+        self.glyph = Some(glyph.clone());
+    }
+}
+
+fn material_app() -> App {
+    let mut app = app(1.);
+    // the material pipeline's shader, loaded by the `RenderPlugin` of a full app
+    app.init_asset::<Shader>()
+        .add_plugins(UiTextMaterialPlugin::<GlyphMaterial>::default());
+    app
+}
+
+/// Runs the material extraction against the app's world, returning the translation, size and
+/// material of each node.
+fn extract_material_nodes(app: &mut App) -> Vec<(Vec2, Vec2, AssetId<GlyphMaterial>)> {
+    let mut render_world = World::new();
+    render_world.init_resource::<ExtractedUiMaterialNodes<GlyphMaterial>>();
+    render_world.init_resource::<MainWorld>();
+    std::mem::swap(
+        app.world_mut(),
+        &mut render_world.resource_mut::<MainWorld>(),
+    );
+    render_world.run_system_once(extract_ui_text_material_nodes::<GlyphMaterial>);
+    std::mem::swap(
+        app.world_mut(),
+        &mut render_world.resource_mut::<MainWorld>(),
+    );
+    render_world
+        .resource::<ExtractedUiMaterialNodes<GlyphMaterial>>()
+        .uinodes
+        .iter()
+        .map(|(_, node)| {
+            (
+                node.transform.w_axis.truncate().truncate(),
+                node.rect.size(),
+                node.material,
+            )
+        })
+        .collect()
+}
+
+fn glyph_materials(app: &App) -> &UiTextGlyphMaterials<GlyphMaterial> {
+    app.world().resource()
+}
+
+#[test]
+fn each_glyph_is_a_material_node_sampling_its_atlas_rect() {
+    let mut app = material_app();
+    let font = font(&mut app);
+    let material = app
+        .world_mut()
+        .resource_mut::<Assets<GlyphMaterial>>()
+        .add(GlyphMaterial::default());
+    let entity = spawn_text(
+        &mut app,
+        UiText::from_section("abca", style(&font, 16.)),
+        (
+            UiTextMaterial(material.clone()),
+            Transform::from_xyz(100., 50., 0.),
+        ),
+    );
+    spawn_text(
+        &mut app,
+        UiText::from_section("abca", style(&font, 16.)),
+        Transform::from_xyz(100., 50., 0.),
+    );
+    app.update();
+
+    // a copy of the material for each distinct glyph
+    assert_eq!(glyph_materials(&app).len(), 3);
+    let text_layout = layout(&app, entity).clone();
+    let materials = app.world().resource::<Assets<GlyphMaterial>>();
+    let atlases = app.world().resource::<Assets<TextureAtlasLayout>>();
+    for glyph in &text_layout.glyphs {
+        let id = glyph_materials(&app)
+            .get(material.id(), glyph, LinearRgba::WHITE)
+            .unwrap();
+        let material_glyph = materials.get(id).unwrap().glyph.clone().unwrap();
+        let atlas = atlases.get(&glyph.atlas_info.texture_atlas).unwrap();
+        let rect = atlas.textures[glyph.atlas_info.glyph_index].as_rect();
+        assert_eq!(material_glyph.texture, glyph.atlas_info.texture);
+        assert_eq!(material_glyph.uv_rect.min * atlas.size.as_vec2(), rect.min);
+        assert_eq!(material_glyph.uv_rect.max * atlas.size.as_vec2(), rect.max);
+        assert_eq!(material_glyph.color, LinearRgba::WHITE);
+    }
+
+    // drawn where the default glyph nodes are, and not as glyph nodes
+    let nodes = extract_material_nodes(&mut app);
+    assert_eq!(nodes.len(), 4);
+    let mut translations: Vec<Vec2> = nodes.iter().map(|(translation, ..)| *translation).collect();
+    translations.sort_by(|a, b| a.x.total_cmp(&b.x));
+    let mut expected: Vec<Vec2> = extract(&mut app)
+        .iter()
+        .map(|(_, node)| node.transform.w_axis.truncate().truncate())
+        .collect();
+    expected.sort_by(|a, b| a.x.total_cmp(&b.x));
+    assert_eq!(translations, expected);
+    // the repeated glyph shares its material
+    let mut ids: Vec<_> = nodes.iter().map(|(.., material)| *material).collect();
+    ids.sort();
+    ids.dedup();
+    assert_eq!(ids.len(), 3);
+}
+
+#[test]
+fn glyph_materials_follow_the_text_material_and_colors() {
+    let mut app = material_app();
+    let font = font(&mut app);
+    let material = app
+        .world_mut()
+        .resource_mut::<Assets<GlyphMaterial>>()
+        .add(GlyphMaterial::default());
+    let entity = spawn_text(
+        &mut app,
+        UiText::from_section("ab", style(&font, 16.)),
+        UiTextMaterial(material.clone()),
+    );
+    app.update();
+    assert_eq!(glyph_materials(&app).len(), 2);
+
+    app.world_mut()
+        .resource_mut::<Assets<GlyphMaterial>>()
+        .get_mut(&material)
+        .unwrap()
+        .threshold = 0.5;
+    // the modification's `AssetEvent` is sent at the end of the frame
+    app.update();
+    app.update();
+    let text_layout = layout(&app, entity).clone();
+    for glyph in &text_layout.glyphs {
+        let id = glyph_materials(&app)
+            .get(material.id(), glyph, LinearRgba::WHITE)
+            .unwrap();
+        let materials = app.world().resource::<Assets<GlyphMaterial>>();
+        assert_eq!(materials.get(id).unwrap().threshold, 0.5);
+    }
+
+    // a new color makes new copies without laying the text out again
+    let red = Color::srgb(1., 0., 0.);
+    let mut text = app.world_mut().get_mut::<UiText>(entity).unwrap();
+    UiText::set_section_color(&mut text, 0, red);
+    app.update();
+    assert_eq!(glyph_materials(&app).len(), 4);
+    for glyph in &text_layout.glyphs {
+        assert!(glyph_materials(&app)
+            .get(material.id(), glyph, red.into())
+            .is_some());
+    }
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_mod_ui_independent_text::*;
+use common::*;
+
+const WORDS: &str = "one two three four five six";
+
+fn glyphs(app: &App, entity: Entity) -> (Vec2, Vec<Vec2>) {
+    let text_layout = layout(app, entity);
+    let positions = text_layout
+        .glyphs
+        .iter()
+        .map(|glyph| glyph.position)
+        .collect();
+    (text_layout.logical_size, positions)
+}
+
+/// Lays out a text with `shared` components, with and without `component`, then checks that
+/// removing the component lays the text out again as if it had never been added.
+fn assert_removal_relays_out<C: Component>(
+    text: impl Fn(&Handle<Font>) -> UiText,
+    shared: impl Bundle + Clone,
+    component: C,
+) {
+    let mut app = app(1.);
+    let font = font(&mut app);
+    let entity = spawn_text(&mut app, text(&font), (shared.clone(), component));
+    let plain = spawn_text(&mut app, text(&font), shared);
+    app.update();
+    assert_ne!(glyphs(&app, entity), glyphs(&app, plain));
+
+    app.world_mut().entity_mut(entity).remove::<C>();
+    app.update();
+    assert_eq!(glyphs(&app, entity), glyphs(&app, plain));
+}
+
+fn words(font: &Handle<Font>) -> UiText {
+    UiText::from_section(WORDS, style(font, 16.))
+}
+
+#[test]
+fn removing_relative_font_size_restores_the_section_font_size() {
+    // twice the `BaseFontSize` of 16
+    assert_removal_relays_out(words, (), RelativeFontSize(2.));
+}
+
+#[test]
+fn removing_bounds_unwraps_the_text() {
+    assert_removal_relays_out(
+        words,
+        (),
+        UiTextBounds {
+            size: Vec2::new(60., f32::INFINITY),
+        },
+    );
+}
+
+#[test]
+fn removing_synthesis_restores_the_bounds() {
+    let mut app = app(1.);
+    let font = font(&mut app);
+    let entity = spawn_text(&mut app, words(&font), ());
+    app.update();
+    // wide enough for the words on one line, but not with the overhang of faux italic glyphs
+    let bounds = UiTextBounds {
+        size: Vec2::new(layout(&app, entity).logical_size.x + 1., f32::INFINITY),
+    };
+    assert_removal_relays_out(
+        words,
+        bounds,
+        UiTextSectionSynthesis::uniform(Synthesis::ITALIC),
+    );
+}
+
+#[test]
+fn removing_direction_restores_left_to_right_justification() {
+    assert_removal_relays_out(
+        |font| UiText::from_section("one two\nthree", style(font, 16.)),
+        (),
+        UiTextDirection::Rtl,
+    );
+}
+
+#[test]
+fn removing_orientation_restores_horizontal_lines() {
+    assert_removal_relays_out(words, (), UiTextOrientation::Vertical);
+}
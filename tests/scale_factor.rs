@@ -0,0 +1,74 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy::ui::ExtractedUiNode;
+use bevy_mod_ui_independent_text::*;
+use common::*;
+
+#[test]
+fn zero_scale_factor_skips_layout_and_extraction() {
+    let mut app = app(1.);
+    let font = font(&mut app);
+    let entity = spawn_text(
+        &mut app,
+        UiText::from_section("zero", style(&font, 16.)),
+        (),
+    );
+    app.update();
+    let size = layout(&app, entity).logical_size;
+    assert_eq!(extract(&mut app).len(), 4);
+
+    set_scale_factor(&mut app, 0.);
+    app.update();
+    // the last layout is kept rather than redone with infinite pixels
+    assert_eq!(layout(&app, entity).logical_size, size);
+    assert!(extract(&mut app).is_empty());
+
+    set_scale_factor(&mut app, 1.);
+    app.update();
+    let nodes = extract(&mut app);
+    assert_eq!(nodes.len(), 4);
+    assert!(nodes
+        .iter()
+        .all(|(_, node)| node.transform.is_finite() && node.rect.size().is_finite()));
+}
+
+/// The atlas rect and atlas size of every extracted glyph, in a stable order.
+fn glyph_rects(nodes: &[(Entity, ExtractedUiNode)]) -> Vec<(Rect, Option<Vec2>)> {
+    let mut rects: Vec<(Rect, Option<Vec2>)> = nodes
+        .iter()
+        .map(|(_, node)| (node.rect, node.atlas_size))
+        .collect();
+    rects.sort_by(|(a, _), (b, _)| {
+        a.min
+            .x
+            .total_cmp(&b.min.x)
+            .then(a.min.y.total_cmp(&b.min.y))
+    });
+    rects
+}
+
+#[test]
+fn glyphs_keep_the_scale_factor_they_were_laid_out_at() {
+    let mut app = app(2.);
+    let font = font(&mut app);
+    let entity = spawn_text(
+        &mut app,
+        UiText::from_section("mismatch", style(&font, 16.)),
+        (),
+    );
+    app.update();
+    app.world_mut().entity_mut(entity).insert(FrozenLayout);
+    let expected = glyph_rects(&extract(&mut app));
+    assert_eq!(expected.len(), 8);
+
+    // drawn by a camera at 1 with the glyphs rasterized at 2
+    set_scale_factor(&mut app, 1.);
+    app.update();
+    assert_eq!(
+        app.world().get::<UiTextLayoutScale>(entity).unwrap().get(),
+        2.
+    );
+    // neither the glyphs nor their atlases are halved or doubled
+    assert_eq!(glyph_rects(&extract(&mut app)), expected);
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "serde")]
+
+mod common;
+
+use std::time::{Duration, Instant};
+
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+use bevy::scene::serde::SceneDeserializer;
+use bevy::text::TextLayoutInfo;
+use bevy_mod_ui_independent_text::*;
+use common::*;
+use serde::de::DeserializeSeed;
+
+/// Updates the app until the font is loaded.
+fn wait_for_font(app: &mut App, font: &Handle<Font>) {
+    let start = Instant::now();
+    while !app.world().resource::<Assets<Font>>().contains(font) {
+        assert!(start.elapsed() < Duration::from_secs(10), "the font loads");
+        app.update();
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn text_entity_round_trips_through_a_dynamic_scene() {
+    let mut app = app(1.);
+    let font = app
+        .world()
+        .resource::<AssetServer>()
+        .load::<Font>("Topaz-8.ttf");
+    let entity = spawn_text(
+        &mut app,
+        UiText::from_section("saved", style(&font, 16.)),
+        UiTextBounds {
+            size: Vec2::new(300., 40.),
+        },
+    );
+    wait_for_font(&mut app, &font);
+    app.update();
+    let saved_size = layout(&app, entity).logical_size;
+    assert_ne!(saved_size, Vec2::ZERO);
+
+    let scene = DynamicSceneBuilder::from_world(app.world())
+        .deny::<TextLayoutInfo>()
+        .extract_entity(entity)
+        .build();
+    let source = scene
+        .serialize(&app.world().resource::<AppTypeRegistry>().read())
+        .unwrap();
+
+    let mut loaded_app = common::app(1.);
+    let scene = SceneDeserializer {
+        type_registry: &loaded_app.world().resource::<AppTypeRegistry>().read(),
+    }
+    .deserialize(&mut ron::Deserializer::from_str(&source).unwrap())
+    .unwrap();
+    let mut entity_map = EntityHashMap::default();
+    scene
+        .write_to_world(loaded_app.world_mut(), &mut entity_map)
+        .unwrap();
+    let loaded = entity_map[&entity];
+    // the scene's handle is replaced by one loaded from the recorded path
+    loaded_app.update();
+    let loaded_font = loaded_app.world().get::<UiText>(loaded).unwrap().sections[0]
+        .style
+        .font
+        .clone();
+    assert_eq!(
+        loaded_font.path().map(ToString::to_string).as_deref(),
+        Some("Topaz-8.ttf")
+    );
+    wait_for_font(&mut loaded_app, &loaded_font);
+    loaded_app.update();
+
+    let text = loaded_app.world().get::<UiText>(loaded).unwrap();
+    assert_eq!(text.sections[0].value, "saved");
+    assert_eq!(
+        loaded_app.world().get::<UiTextBounds>(loaded).unwrap().size,
+        Vec2::new(300., 40.)
+    );
+    assert_eq!(layout(&loaded_app, loaded).logical_size, saved_size);
+    assert_eq!(layout(&loaded_app, loaded).glyphs.len(), 5);
+    assert_eq!(extract(&mut loaded_app).len(), 5);
+}
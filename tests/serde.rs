@@ -0,0 +1,69 @@
+#![cfg(feature = "serde")]
+
+mod common;
+
+use bevy::prelude::*;
+use bevy::text::BreakLineOn;
+use bevy_mod_ui_independent_text::*;
+use common::*;
+use serde::de::DeserializeSeed;
+
+#[test]
+fn multi_section_text_round_trips_through_ron() {
+    let app = app(1.);
+    let asset_server = app.world().resource::<AssetServer>().clone();
+    let font: Handle<Font> = asset_server.load("Topaz-8.ttf");
+    let mut text = UiText::from_sections([
+        TextSection::new(
+            "Deal ",
+            TextStyle {
+                font: font.clone(),
+                font_size: 16.,
+                color: Color::WHITE,
+            },
+        ),
+        TextSection::new(
+            "25",
+            TextStyle {
+                font: font.clone(),
+                font_size: 24.,
+                color: Color::srgb(1., 0., 0.),
+            },
+        ),
+        TextSection::new(
+            " damage",
+            TextStyle {
+                font_size: 12.,
+                color: Color::srgba(0., 0., 1., 0.5),
+                ..default()
+            },
+        ),
+    ]);
+    text.justify = JustifyText::Right;
+    text.linebreak_behavior = BreakLineOn::AnyCharacter;
+
+    let source = ron::to_string(&text).unwrap();
+    let round_trip = UiTextSeed(&asset_server)
+        .deserialize(&mut ron::Deserializer::from_str(&source).unwrap())
+        .unwrap();
+
+    assert_eq!(round_trip.justify, JustifyText::Right);
+    assert_eq!(round_trip.linebreak_behavior, BreakLineOn::AnyCharacter);
+    assert_eq!(round_trip.sections.len(), 3);
+    for (a, b) in text.sections.iter().zip(&round_trip.sections) {
+        assert_eq!(a.value, b.value);
+        assert_eq!(a.style.font_size, b.style.font_size);
+        assert_eq!(a.style.color, b.style.color);
+        assert_eq!(a.style.font.path(), b.style.font.path());
+    }
+    assert_eq!(round_trip.sections[2].style.font, Handle::default());
+}
+
+#[test]
+fn components_serialize_as_plain_data() {
+    let bounds = UiTextBounds {
+        size: Vec2::new(200., 50.),
+    };
+    let source = ron::to_string(&bounds).unwrap();
+    assert_eq!(ron::from_str::<UiTextBounds>(&source).unwrap(), bounds);
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy::text::TextLayoutInfo;
+use bevy_mod_ui_independent_text::*;
+use common::*;
+
+#[test]
+fn text_2d_is_laid_out_by_bevy_alongside_ui_text() {
+    let mut app = app(1.);
+    let font = font(&mut app);
+    let ui_text = spawn_text(
+        &mut app,
+        UiText::from_section("independent", style(&font, 16.)),
+        Transform::from_xyz(100., 50., 0.),
+    );
+    let text_2d = app
+        .world_mut()
+        .spawn(Text2dBundle {
+            text: Text::from_section("bevy", style(&font, 16.)),
+            ..default()
+        })
+        .id();
+    app.update();
+
+    // Bevy's own layout of the `Text2d`, which the crate doesn't draw
+    assert_eq!(
+        app.world()
+            .get::<TextLayoutInfo>(text_2d)
+            .unwrap()
+            .glyphs
+            .len(),
+        4
+    );
+    assert_eq!(layout(&app, ui_text).glyphs.len(), 11);
+
+    // only the `UiText`'s glyphs, once each, frame after frame
+    for _ in 0..2 {
+        assert_eq!(extract(&mut app).len(), 11);
+        app.update();
+    }
+}